@@ -15,8 +15,11 @@ async fn main() -> Result<()> {
     for item in featured.featured_linux {
         if item.discounted {
             println!(
-                "{:#?} - {:#?} - {:#?}%",
-                item.app_id, item.name, item.discount_percent
+                "{:#?} - {:#?} - {:#?}% - {}",
+                item.app_id,
+                item.name,
+                item.discount_percent,
+                item.final_money()
             );
         }
     }
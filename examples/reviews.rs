@@ -1,6 +1,7 @@
 extern crate steam_store_api;
 
 use anyhow::Result;
+use futures::StreamExt;
 use steam_store_api::prelude::*;
 
 #[tokio::main]
@@ -10,7 +11,7 @@ async fn main() -> Result<()> {
         .with_language(&Language::English)
         .build()?;
 
-    let mut filter = ReviewsFilter {
+    let filter = ReviewsFilter {
         language: Some(Language::English),
         day_range: Some(365),
         num_per_page: Some(100),
@@ -18,23 +19,39 @@ async fn main() -> Result<()> {
         ..Default::default()
     };
 
-    let mut reviews_data = client.reviews(&489830_u64, &filter).await?;
-    loop {
-        for review in reviews_data.reviews {
-            if review.votes_funny > 100 {
-                println!(
-                    "Votes funny: {:#?}\nReview: {:#?}\n",
-                    &review.votes_funny, &review.review
-                );
-            }
-        }
-        if reviews_data.query_summary.num_reviews < 100 {
-            break;
+    // pages are fetched as the stream is consumed
+    let mut reviews = client
+        .reviews_stream(&489830_u64, &filter)
+        .with_max_reviews(1000);
+
+    while let Some(review) = reviews.next().await {
+        let review = review?;
+        if review.votes_funny > 100 {
+            println!(
+                "Votes funny: {:#?}\nReview: {:#?}\n",
+                &review.votes_funny, &review.review
+            );
         }
+    }
 
-        // send cursor inside filter for the next page
-        filter.cursor = Some(reviews_data.cursor);
-        reviews_data = client.reviews(&489830_u64, &filter).await?;
+    if let Some(summary) = reviews.summary() {
+        println!("Total reviews: {:?}", summary.total_reviews);
+    }
+
+    // summaries only, fetched concurrently
+    let badges = client.review_summaries([489830_u64, 219990, 1091500]).await;
+    for (app_id, summary) in &badges.summaries {
+        if let Some(score) = summary.score() {
+            println!(
+                "{}: {} ({})",
+                app_id,
+                score.as_ref(),
+                summary.sentiment().total()
+            );
+        }
+    }
+    for (app_id, err) in &badges.failed {
+        println!("{}: {}", app_id, err);
     }
 
     Ok(())
@@ -0,0 +1,505 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::release::Date;
+use crate::review::{QuerySummary, Review};
+
+/// Reviews needed before Steam rates an app.
+const MIN_RATED_REVIEWS: u64 = 10;
+
+/// Positive and negative review counts.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Sentiment {
+    pub positive: u64,
+    pub negative: u64,
+}
+
+impl Sentiment {
+    pub fn total(&self) -> u64 {
+        self.positive + self.negative
+    }
+
+    /// Share of positive reviews from 0 to 1, `None` without reviews.
+    pub fn positive_ratio(&self) -> Option<f64> {
+        (self.total() > 0).then(|| self.positive as f64 / self.total() as f64)
+    }
+
+    /// Rating Steam shows for these counts, `None` under 10 reviews.
+    pub fn review_score(&self) -> Option<ReviewScore> {
+        ReviewScore::classify(self.positive, self.negative)
+    }
+
+    /// Lower bound of the Wilson score interval of the positive ratio,
+    /// `z` is the normal quantile, e.g. 1.96 for 95% confidence.
+    /// Ranks apps with few reviews below equally rated apps with many; 0 without reviews.
+    pub fn wilson_lower_bound(&self, z: f64) -> f64 {
+        let n = self.total() as f64;
+        if n == 0.0 {
+            return 0.0;
+        }
+        let p = self.positive as f64 / n;
+        let z2 = z * z;
+
+        let center = p + z2 / (2.0 * n);
+        let margin = z * ((p * (1.0 - p) + z2 / (4.0 * n)) / n).sqrt();
+        ((center - margin) / (1.0 + z2 / n)).max(0.0)
+    }
+
+    /// Positive ratio pulled towards `prior_ratio` as if `prior_weight` more
+    /// reviews had that ratio, e.g. the average over all ranked apps.
+    pub fn bayesian_average(&self, prior_ratio: f64, prior_weight: f64) -> f64 {
+        let weight = self.total() as f64 + prior_weight;
+        if weight <= 0.0 {
+            return prior_ratio;
+        }
+        (self.positive as f64 + prior_ratio * prior_weight) / weight
+    }
+
+    fn add(&mut self, review: &Review) {
+        if review.voted_up {
+            self.positive += 1;
+        } else {
+            self.negative += 1;
+        }
+    }
+}
+
+impl From<&QuerySummary> for Sentiment {
+    fn from(summary: &QuerySummary) -> Self {
+        Sentiment {
+            positive: summary.total_positive.unwrap_or_default() as u64,
+            negative: summary.total_negative.unwrap_or_default() as u64,
+        }
+    }
+}
+
+impl QuerySummary {
+    /// Review counts of the summary, missing totals count as 0.
+    pub fn sentiment(&self) -> Sentiment {
+        Sentiment::from(self)
+    }
+
+    /// Rating computed from `total_positive` and `total_negative`,
+    /// independent of the language of `review_score_desc`.
+    pub fn score(&self) -> Option<ReviewScore> {
+        self.sentiment().review_score()
+    }
+}
+
+/// Steam's rating of an app, ordered from worst to best.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReviewScore {
+    OverwhelminglyNegative,
+    VeryNegative,
+    Negative,
+    MostlyNegative,
+    Mixed,
+    MostlyPositive,
+    Positive,
+    VeryPositive,
+    OverwhelminglyPositive,
+}
+
+impl ReviewScore {
+    /// Applies Steam's thresholds, `None` under 10 reviews.
+    pub fn classify(positive: u64, negative: u64) -> Option<Self> {
+        use ReviewScore::*;
+
+        let total = positive + negative;
+        if total < MIN_RATED_REVIEWS {
+            return None;
+        }
+        let percent = positive * 100 / total;
+
+        Some(match (percent, total) {
+            (95.., 500..) => OverwhelminglyPositive,
+            (80.., 50..) => VeryPositive,
+            (80.., _) => Positive,
+            (70.., _) => MostlyPositive,
+            (40.., _) => Mixed,
+            (20.., _) => MostlyNegative,
+            (_, 500..) => OverwhelminglyNegative,
+            (_, 50..) => VeryNegative,
+            _ => Negative,
+        })
+    }
+
+    /// Reads `QuerySummary::review_score`, from 1 to 9.
+    pub fn from_score(score: u32) -> Option<Self> {
+        use ReviewScore::*;
+
+        Some(match score {
+            1 => OverwhelminglyNegative,
+            2 => VeryNegative,
+            3 => Negative,
+            4 => MostlyNegative,
+            5 => Mixed,
+            6 => MostlyPositive,
+            7 => Positive,
+            8 => VeryPositive,
+            9 => OverwhelminglyPositive,
+            _ => return None,
+        })
+    }
+
+    /// Numeric score as in `QuerySummary::review_score`.
+    pub fn score(&self) -> u32 {
+        *self as u32 + 1
+    }
+}
+
+impl AsRef<str> for ReviewScore {
+    fn as_ref(&self) -> &str {
+        use ReviewScore::*;
+
+        match &self {
+            OverwhelminglyNegative => "Overwhelmingly Negative",
+            VeryNegative => "Very Negative",
+            Negative => "Negative",
+            MostlyNegative => "Mostly Negative",
+            Mixed => "Mixed",
+            MostlyPositive => "Mostly Positive",
+            Positive => "Positive",
+            VeryPositive => "Very Positive",
+            OverwhelminglyPositive => "Overwhelmingly Positive",
+        }
+    }
+}
+
+/// Length of the time buckets of [`ReviewAnalytics::sentiment_by_period`].
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Period {
+    Day,
+    /// Weeks starting on Monday
+    Week,
+    Month,
+    Year,
+}
+
+impl Period {
+    /// First day of the bucket containing the Unix timestamp, in UTC.
+    pub fn start_of(&self, secs: u64) -> Date {
+        let days = (secs / 86_400) as i64;
+        let date = Date::from_days(days);
+
+        match self {
+            Period::Day => date,
+            // 1970-01-01 was a Thursday
+            Period::Week => Date::from_days(days - (days + 3).rem_euclid(7)),
+            Period::Month => Date { day: 1, ..date },
+            Period::Year => Date {
+                month: 1,
+                day: 1,
+                ..date
+            },
+        }
+    }
+}
+
+/// Distribution of playtime at review time.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct PlaytimeQuantiles {
+    pub count: usize,
+    pub min: Duration,
+    pub p25: Duration,
+    pub median: Duration,
+    pub p75: Duration,
+    pub p90: Duration,
+    pub max: Duration,
+}
+
+impl PlaytimeQuantiles {
+    /// `None` without reviews.
+    fn of<'a, I: IntoIterator<Item = &'a Review>>(reviews: I) -> Option<Self> {
+        let mut minutes: Vec<u64> = reviews
+            .into_iter()
+            .map(|r| r.author.playtime_at_review)
+            .collect();
+        if minutes.is_empty() {
+            return None;
+        }
+        minutes.sort_unstable();
+
+        // nearest-rank method
+        let at = |q: f64| {
+            let rank = (q * minutes.len() as f64).ceil() as usize;
+            Duration::from_secs(minutes[rank.clamp(1, minutes.len()) - 1] * 60)
+        };
+
+        Some(PlaytimeQuantiles {
+            count: minutes.len(),
+            min: at(0.0),
+            p25: at(0.25),
+            median: at(0.5),
+            p75: at(0.75),
+            p90: at(0.9),
+            max: at(1.0),
+        })
+    }
+}
+
+/// Playtime quantiles of positive and negative reviews.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct PlaytimeByVote {
+    pub voted_up: Option<PlaytimeQuantiles>,
+    pub voted_down: Option<PlaytimeQuantiles>,
+}
+
+/// Sentiment and playtime of a group of reviews.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Cohort {
+    pub sentiment: Sentiment,
+    pub playtime: Option<PlaytimeQuantiles>,
+}
+
+impl Cohort {
+    fn of<'a, I: Iterator<Item = &'a Review> + Clone>(reviews: I) -> Self {
+        let mut sentiment = Sentiment::default();
+        reviews.clone().for_each(|r| sentiment.add(r));
+
+        Cohort {
+            sentiment,
+            playtime: PlaytimeQuantiles::of(reviews),
+        }
+    }
+}
+
+/// Reviews written during early access compared to those written after launch.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct EarlyAccessComparison {
+    pub early_access: Cohort,
+    pub after_launch: Cohort,
+}
+
+/// Reviews of free keys compared to those of paying users.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct FreeKeyComparison {
+    pub received_for_free: Cohort,
+    pub purchased: Cohort,
+}
+
+/// Statistics over a set of fetched reviews.
+pub trait ReviewAnalytics {
+    fn sentiment(&self) -> Sentiment;
+
+    /// Sentiment per bucket of creation time, keyed by the first day of the bucket.
+    fn sentiment_by_period(&self, period: Period) -> BTreeMap<Date, Sentiment>;
+
+    /// Sentiment per review language, e.g. `english`.
+    fn sentiment_by_language(&self) -> BTreeMap<String, Sentiment>;
+
+    fn playtime_by_vote(&self) -> PlaytimeByVote;
+
+    fn early_access_comparison(&self) -> EarlyAccessComparison;
+
+    fn free_key_comparison(&self) -> FreeKeyComparison;
+}
+
+impl ReviewAnalytics for [Review] {
+    fn sentiment(&self) -> Sentiment {
+        let mut out = Sentiment::default();
+        self.iter().for_each(|r| out.add(r));
+        out
+    }
+
+    fn sentiment_by_period(&self, period: Period) -> BTreeMap<Date, Sentiment> {
+        let mut out: BTreeMap<Date, Sentiment> = BTreeMap::new();
+        for review in self {
+            out.entry(period.start_of(review.timestamp_created))
+                .or_default()
+                .add(review);
+        }
+        out
+    }
+
+    fn sentiment_by_language(&self) -> BTreeMap<String, Sentiment> {
+        let mut out: BTreeMap<String, Sentiment> = BTreeMap::new();
+        for review in self {
+            out.entry(review.language.clone()).or_default().add(review);
+        }
+        out
+    }
+
+    fn playtime_by_vote(&self) -> PlaytimeByVote {
+        PlaytimeByVote {
+            voted_up: PlaytimeQuantiles::of(self.iter().filter(|r| r.voted_up)),
+            voted_down: PlaytimeQuantiles::of(self.iter().filter(|r| !r.voted_up)),
+        }
+    }
+
+    fn early_access_comparison(&self) -> EarlyAccessComparison {
+        EarlyAccessComparison {
+            early_access: Cohort::of(self.iter().filter(|r| r.written_during_early_access)),
+            after_launch: Cohort::of(self.iter().filter(|r| !r.written_during_early_access)),
+        }
+    }
+
+    fn free_key_comparison(&self) -> FreeKeyComparison {
+        FreeKeyComparison {
+            received_for_free: Cohort::of(self.iter().filter(|r| r.received_for_free)),
+            purchased: Cohort::of(self.iter().filter(|r| !r.received_for_free)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+
+    /// 2024-01-01, a Monday, 00:00 UTC
+    const JAN_1_2024: u64 = 1_704_067_200;
+    const DAY: u64 = 86_400;
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    fn reviews(minutes: &[u64]) -> Vec<Review> {
+        minutes
+            .iter()
+            .enumerate()
+            .map(|(i, m)| {
+                let mut review = mock::review(&i.to_string(), JAN_1_2024, JAN_1_2024);
+                review["author"]["playtime_at_review"] = (*m).into();
+                serde_json::from_value(review).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn weeks_start_on_monday() {
+        let week = |secs| Period::Week.start_of(secs);
+
+        assert_eq!(week(JAN_1_2024), date(2024, 1, 1));
+        assert_eq!(week(JAN_1_2024 + 7 * DAY - 1), date(2024, 1, 1));
+        assert_eq!(week(JAN_1_2024 + 7 * DAY), date(2024, 1, 8));
+        // Sunday before rolls back into the previous year.
+        assert_eq!(week(JAN_1_2024 - 1), date(2023, 12, 25));
+        assert_eq!(week(JAN_1_2024 - 6 * DAY), date(2023, 12, 25));
+        // The epoch was a Thursday.
+        assert_eq!(week(0), date(1969, 12, 29));
+    }
+
+    #[test]
+    fn months_and_years() {
+        let mar_1_2024 = JAN_1_2024 + (31 + 29) * DAY;
+
+        assert_eq!(Period::Month.start_of(mar_1_2024 - 1), date(2024, 2, 1));
+        assert_eq!(Period::Month.start_of(mar_1_2024), date(2024, 3, 1));
+        assert_eq!(Period::Month.start_of(JAN_1_2024 - 1), date(2023, 12, 1));
+        assert_eq!(Period::Year.start_of(JAN_1_2024 - 1), date(2023, 1, 1));
+        assert_eq!(Period::Year.start_of(mar_1_2024), date(2024, 1, 1));
+        assert_eq!(Period::Day.start_of(mar_1_2024 - 1), date(2024, 2, 29));
+    }
+
+    #[test]
+    fn quantiles() {
+        let minutes = |d: Duration| d.as_secs() / 60;
+
+        assert_eq!(PlaytimeQuantiles::of(&reviews(&[])), None);
+
+        let single = PlaytimeQuantiles::of(&reviews(&[30])).unwrap();
+        assert_eq!(single.count, 1);
+        for q in [
+            single.min,
+            single.p25,
+            single.median,
+            single.p75,
+            single.p90,
+            single.max,
+        ] {
+            assert_eq!(minutes(q), 30);
+        }
+
+        let even = PlaytimeQuantiles::of(&reviews(&[40, 10, 30, 20])).unwrap();
+        assert_eq!(even.count, 4);
+        assert_eq!(
+            [
+                even.min,
+                even.p25,
+                even.median,
+                even.p75,
+                even.p90,
+                even.max
+            ]
+            .map(minutes),
+            [10, 10, 20, 30, 40, 40]
+        );
+    }
+
+    #[test]
+    fn playtime_by_vote() {
+        let mut reviews = reviews(&[10, 20, 30]);
+        reviews[1].voted_up = false;
+
+        let by_vote = reviews.playtime_by_vote();
+        assert_eq!(by_vote.voted_up.unwrap().count, 2);
+        assert_eq!(
+            by_vote.voted_down.unwrap().median,
+            Duration::from_secs(20 * 60)
+        );
+    }
+
+    #[test]
+    fn review_score_thresholds() {
+        use ReviewScore::*;
+        let classify = |positive, negative| ReviewScore::classify(positive, negative);
+
+        // 10 reviews
+        assert_eq!(classify(9, 0), None);
+        assert_eq!(classify(10, 0), Some(Positive));
+        assert_eq!(classify(0, 10), Some(Negative));
+        // 50 reviews
+        assert_eq!(classify(40, 9), Some(Positive));
+        assert_eq!(classify(40, 10), Some(VeryPositive));
+        assert_eq!(classify(0, 49), Some(Negative));
+        assert_eq!(classify(0, 50), Some(VeryNegative));
+        // 500 reviews
+        assert_eq!(classify(475, 24), Some(VeryPositive));
+        assert_eq!(classify(475, 25), Some(OverwhelminglyPositive));
+        assert_eq!(classify(470, 30), Some(VeryPositive));
+        assert_eq!(classify(95, 404), Some(VeryNegative));
+        assert_eq!(classify(95, 405), Some(OverwhelminglyNegative));
+        // percentages at 100 reviews
+        assert_eq!(classify(95, 5), Some(VeryPositive));
+        assert_eq!(classify(80, 20), Some(VeryPositive));
+        assert_eq!(classify(79, 21), Some(MostlyPositive));
+        assert_eq!(classify(70, 30), Some(MostlyPositive));
+        assert_eq!(classify(69, 31), Some(Mixed));
+        assert_eq!(classify(40, 60), Some(Mixed));
+        assert_eq!(classify(39, 61), Some(MostlyNegative));
+        assert_eq!(classify(20, 80), Some(MostlyNegative));
+        assert_eq!(classify(19, 81), Some(VeryNegative));
+        // percentages below 50 reviews
+        assert_eq!(classify(8, 2), Some(Positive));
+        assert_eq!(classify(7, 3), Some(MostlyPositive));
+        assert_eq!(classify(4, 6), Some(Mixed));
+        assert_eq!(classify(2, 8), Some(MostlyNegative));
+        assert_eq!(classify(1, 9), Some(Negative));
+    }
+
+    #[test]
+    fn review_score_numbers() {
+        for score in 1..=9 {
+            assert_eq!(ReviewScore::from_score(score).unwrap().score(), score);
+        }
+        assert_eq!(ReviewScore::from_score(0), None);
+        assert_eq!(ReviewScore::from_score(10), None);
+    }
+
+    #[test]
+    fn wilson_lower_bound() {
+        let sentiment = |positive, negative| Sentiment { positive, negative };
+
+        assert_eq!(sentiment(0, 0).wilson_lower_bound(1.96), 0.0);
+        assert_eq!(sentiment(0, 10).wilson_lower_bound(1.96), 0.0);
+        assert!((sentiment(8, 2).wilson_lower_bound(1.96) - 0.4902).abs() < 1e-4);
+        assert!(
+            sentiment(80, 20).wilson_lower_bound(1.96) > sentiment(8, 2).wilson_lower_bound(1.96)
+        );
+        assert!(sentiment(1, 0).wilson_lower_bound(1.96) < 1.0);
+    }
+}
@@ -4,8 +4,11 @@ use serde::{Deserialize, Serialize};
 use serde_aux::prelude::*;
 
 use crate::{
+    category::{Category, ContentDescriptor, GenreId},
+    html::strip_tags,
+    lenient::Extra,
     price::AppPrice,
-    types::{Platforms, ReleaseDate},
+    types::{AppType, ItemType, Language, Platforms, ReleaseDate},
 };
 
 #[derive(Deserialize)]
@@ -15,12 +18,11 @@ pub(crate) struct AppData {
 }
 
 /// Contains information about steam application
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct AppDetails {
-    /// Observed values: "game", "dlc", "demo", "advertising", "mod", "video".
-    pub r#type: String,
+    pub r#type: AppType,
     pub name: String,
-    #[serde(rename(deserialize = "steam_appid"))]
+    #[serde(rename(deserialize = "steam_appid"), alias = "app_id")]
     pub app_id: u64,
     #[serde(deserialize_with = "deserialize_option_number_from_string")]
     pub required_age: Option<u8>,
@@ -60,86 +62,206 @@ pub struct AppDetails {
     pub background: Option<String>,
     pub background_raw: Option<String>,
     pub content_descriptors: Option<ContentDescriptors>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+impl AppDetails {
+    pub fn is_type(&self, app_type: &AppType) -> bool {
+        &self.r#type == app_type
+    }
+
+    pub fn has_category(&self, category: Category) -> bool {
+        self.categories.iter().flatten().any(|c| c.id == category)
+    }
+
+    pub fn has_genre(&self, genre: GenreId) -> bool {
+        self.genres
+            .iter()
+            .flatten()
+            .any(|g| g.genre_id() == Some(genre))
+    }
+
+    pub fn is_multiplayer(&self) -> bool {
+        self.categories
+            .iter()
+            .flatten()
+            .any(|c| c.id.is_multiplayer())
+    }
+
+    pub fn supports_vr(&self) -> bool {
+        self.categories.iter().flatten().any(|c| c.id.is_vr())
+    }
+
+    /// Parsed `supported_languages`, see [`parse_supported_languages`].
+    pub fn languages(&self) -> Vec<LanguageSupport> {
+        self.supported_languages
+            .as_deref()
+            .map(parse_supported_languages)
+            .unwrap_or_default()
+    }
+}
+
+/// Language support of an application.
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LanguageSupport {
+    pub language: Language,
+    /// Always `true` for languages listed in `supported_languages`
+    pub interface: bool,
+    pub full_audio: bool,
+    /// `None` when the store does not report it,
+    /// which is always the case for `supported_languages`.
+    pub subtitles: Option<bool>,
+}
+
+/// Parses `supported_languages` HTML, e.g.
+/// `English<strong>*</strong>, French<br><strong>*</strong>languages with full audio support`.
+///
+/// Names are matched with [`Language::from_name`], so the request
+/// language should be English or the list should use native names.
+/// Languages the crate does not know about are skipped.
+pub fn parse_supported_languages(html: &str) -> Vec<LanguageSupport> {
+    let list = html.split("<br").next().unwrap_or_default();
+    let mut out: Vec<LanguageSupport> = Vec::new();
+
+    for item in list.split(',') {
+        let text = strip_tags(item);
+        let full_audio = text.contains('*');
+        let name = text.replace('*', "");
+
+        if let Some(language) = Language::from_name(&name) {
+            if out.iter().any(|l| l.language == language) {
+                continue;
+            }
+            out.push(LanguageSupport {
+                language,
+                interface: true,
+                full_audio,
+                subtitles: None,
+            });
+        }
+    }
+
+    out
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ContentDescriptors {
-    pub ids: Vec<u64>,
+    pub ids: Vec<ContentDescriptor>,
     pub notes: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct SupportInfo {
     pub url: String,
     pub email: String,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Achievement {
     pub name: String,
     pub path: String,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Achievements {
     pub total: u32,
     pub highlighted: Option<Vec<Achievement>>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct MovieFormat {
-    #[serde(rename(deserialize = "480"))]
+    #[serde(rename(deserialize = "480"), alias = "x480")]
     pub x480: String,
     pub max: String,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Movie {
     pub id: u64,
     pub name: String,
     pub thumbnail: String,
     pub highlight: bool,
-    pub webm: MovieFormat,
-    pub mp4: MovieFormat,
+    /// Legacy progressive download, absent in newer responses.
+    pub webm: Option<MovieFormat>,
+    /// Legacy progressive download, absent in newer responses.
+    pub mp4: Option<MovieFormat>,
+    /// DASH manifest URL with AV1 video.
+    pub dash_av1: Option<String>,
+    /// DASH manifest URL with H.264 video.
+    pub dash_h264: Option<String>,
+    /// HLS playlist URL with H.264 video.
+    pub hls_h264: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Screenshot {
     pub id: u64,
     pub path_thumbnail: String,
     pub path_full: String,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Metacritic {
     pub score: u8,
     pub url: String,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Requirements {
     pub minimum: Option<String>,
     pub recommended: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Recommendations {
     pub total: u64,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Categorie {
     /// Steam categorie id
-    pub id: u8,
+    pub id: Category,
     pub description: String,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Genre {
     /// Steam genre id
     pub id: String,
     pub name: Option<String>,
     pub description: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl Genre {
+    /// Parsed `id`, either numeric or a slug like `action`.
+    pub fn genre_id(&self) -> Option<GenreId> {
+        self.id.parse().ok()
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -148,16 +270,18 @@ pub(crate) struct GenreData {
     pub genres: Vec<Genre>,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct App {
-    #[serde(rename(deserialize = "appid"))]
+    #[serde(rename(deserialize = "appid"), alias = "app_id")]
     /// Steam application id
     pub app_id: u64,
     /// Application name
     pub name: String,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct AppsIn {
     pub(crate) status: i8,
     /// ID of genre or category
@@ -166,20 +290,32 @@ pub struct AppsIn {
     pub name: String,
     /// List of tabs with items, e.g. Top Sellers, Specials
     pub tabs: Option<HashMap<String, Tab>>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Tab {
     pub name: String,
     pub total_item_count: u64,
     pub items: Vec<TabItem>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl Tab {
+    pub fn items_of_type(&self, item_type: ItemType) -> impl Iterator<Item = &TabItem> {
+        self.items.iter().filter(move |i| i.r#type == item_type)
+    }
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct TabItem {
-    /// Game, dlc, movie etc
-    pub r#type: u8,
+    /// App, package or bundle
+    pub r#type: ItemType,
     /// Application id
-    #[serde(rename(deserialize = "id"))]
+    #[serde(rename(deserialize = "id"), alias = "app_id")]
     pub app_id: u64,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
@@ -0,0 +1,219 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::steam::SteamErr;
+
+macro_rules! id_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident($repr:ty) { $($(#[$vmeta:meta])* $variant:ident = $id:literal,)* }
+    ) => {
+        $(#[$meta])*
+        #[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        #[serde(from = "u32", into = "u32")]
+        pub enum $name {
+            $($(#[$vmeta])* $variant,)*
+            /// Any id not known to this crate.
+            Unknown($repr),
+        }
+
+        impl $name {
+            /// Every known variant.
+            pub const ALL: &'static [$name] = &[$($name::$variant,)*];
+
+            /// Steam id
+            pub fn id(&self) -> $repr {
+                match self {
+                    $($name::$variant => $id,)*
+                    $name::Unknown(id) => *id,
+                }
+            }
+        }
+
+        impl From<$repr> for $name {
+            fn from(id: $repr) -> Self {
+                match id {
+                    $($id => $name::$variant,)*
+                    id => $name::Unknown(id),
+                }
+            }
+        }
+
+        impl From<$name> for $repr {
+            fn from(v: $name) -> Self {
+                v.id()
+            }
+        }
+    };
+}
+
+id_enum! {
+    /// Well-known Steam store categories (features), e.g. Single-player or Steam Cloud.
+    Category(u32) {
+        MultiPlayer = 1,
+        SinglePlayer = 2,
+        ValveAntiCheat = 8,
+        CoOp = 9,
+        GameDemo = 10,
+        CaptionsAvailable = 13,
+        Commentary = 14,
+        Stats = 15,
+        SourceSdk = 16,
+        LevelEditor = 17,
+        PartialControllerSupport = 18,
+        Mods = 19,
+        Mmo = 20,
+        DownloadableContent = 21,
+        Achievements = 22,
+        SteamCloud = 23,
+        SharedSplitScreen = 24,
+        Leaderboards = 25,
+        CrossPlatformMultiplayer = 27,
+        FullControllerSupport = 28,
+        TradingCards = 29,
+        Workshop = 30,
+        VrSupport = 31,
+        TurnNotifications = 32,
+        InAppPurchases = 35,
+        OnlinePvp = 36,
+        SharedSplitScreenPvp = 37,
+        OnlineCoOp = 38,
+        SharedSplitScreenCoOp = 39,
+        SteamVrCollectibles = 40,
+        RemotePlayOnPhone = 41,
+        RemotePlayOnTablet = 42,
+        RemotePlayOnTv = 43,
+        RemotePlayTogether = 44,
+        LanPvp = 47,
+        LanCoOp = 48,
+        Pvp = 49,
+        SteamChinaWorkshop = 50,
+        TrackedControllerSupport = 52,
+        VrSupported = 53,
+        VrOnly = 54,
+        HdrAvailable = 61,
+        FamilySharing = 62,
+        Timeline = 63,
+    }
+}
+
+id_enum! {
+    /// Well-known Steam store genres.
+    GenreId(u32) {
+        Action = 1,
+        Strategy = 2,
+        Rpg = 3,
+        Casual = 4,
+        Racing = 9,
+        Sports = 18,
+        Indie = 23,
+        Adventure = 25,
+        Simulation = 28,
+        MassivelyMultiplayer = 29,
+        FreeToPlay = 37,
+        AnimationModeling = 51,
+        AudioProduction = 52,
+        DesignIllustration = 53,
+        Education = 54,
+        PhotoEditing = 55,
+        SoftwareTraining = 56,
+        Utilities = 57,
+        VideoProduction = 58,
+        WebPublishing = 59,
+        GameDevelopment = 60,
+        EarlyAccess = 70,
+        SexualContent = 71,
+        Nudity = 72,
+        Violent = 73,
+        Gore = 74,
+        Documentary = 81,
+        Tutorial = 84,
+    }
+}
+
+id_enum! {
+    /// Mature content descriptors declared by developers.
+    ContentDescriptor(u32) {
+        SomeNudityOrSexualContent = 1,
+        FrequentViolenceOrGore = 2,
+        AdultOnlySexualContent = 3,
+        GratuitousSexualContent = 4,
+        GeneralMatureContent = 5,
+    }
+}
+
+impl Category {
+    /// Any kind of multiplayer: online, LAN or shared screen.
+    pub fn is_multiplayer(&self) -> bool {
+        use Category::*;
+
+        matches!(
+            self,
+            MultiPlayer
+                | CoOp
+                | Mmo
+                | SharedSplitScreen
+                | CrossPlatformMultiplayer
+                | OnlinePvp
+                | SharedSplitScreenPvp
+                | OnlineCoOp
+                | SharedSplitScreenCoOp
+                | LanPvp
+                | LanCoOp
+                | Pvp
+                | RemotePlayTogether
+        )
+    }
+
+    /// Any kind of VR support.
+    pub fn is_vr(&self) -> bool {
+        use Category::*;
+
+        matches!(
+            self,
+            VrSupport | VrSupported | VrOnly | TrackedControllerSupport | SteamVrCollectibles
+        )
+    }
+}
+
+impl GenreId {
+    /// Genre slug used by `getappsingenre`, e.g. `action` or `rpg`.
+    pub fn slug(&self) -> Option<&'static str> {
+        use GenreId::*;
+
+        match self {
+            Action => Some("action"),
+            Strategy => Some("strategy"),
+            Rpg => Some("rpg"),
+            Casual => Some("casual"),
+            Racing => Some("racing"),
+            Sports => Some("sports"),
+            Indie => Some("indie"),
+            Adventure => Some("adventure"),
+            Simulation => Some("simulation"),
+            MassivelyMultiplayer => Some("massively"),
+            FreeToPlay => Some("freetoplay"),
+            EarlyAccess => Some("earlyaccess"),
+            _ => None,
+        }
+    }
+}
+
+/// Accepts numeric ids, as in `appdetails`, and slugs, as in `getgenrelist`.
+impl FromStr for GenreId {
+    type Err = SteamErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(id) = s.parse::<u32>() {
+            return Ok(GenreId::from(id));
+        }
+
+        let s = s.to_lowercase();
+        GenreId::ALL
+            .iter()
+            .find(|g| g.slug() == Some(s.as_str()))
+            .copied()
+            .ok_or_else(|| SteamErr::UnknownGenre(s))
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::money::Currency;
+use crate::steam::SteamErr;
+
+/// ISO 3166-1 alpha-2 country code, e.g. `US`, validated on creation.
+#[derive(Deserialize, Serialize, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(try_from = "String", into = "String")]
+pub struct CountryCode([u8; 2]);
+
+/// Pricing region the store assigns to a country.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum PricingRegion {
+    /// Region with its own currency, e.g. the euro area or Japan.
+    /// Countries without a regional price fall back to `Currency(Usd)`.
+    Currency(Currency),
+    /// Commonwealth of Independent States, priced in USD.
+    CisUsd,
+    /// South Asia, priced in USD.
+    SouthAsiaUsd,
+    /// Latin America, priced in USD.
+    LatamUsd,
+    /// Middle East and North Africa, priced in USD.
+    MenaUsd,
+}
+
+impl PricingRegion {
+    pub fn currency(&self) -> Currency {
+        match self {
+            PricingRegion::Currency(c) => *c,
+            _ => Currency::Usd,
+        }
+    }
+}
+
+impl CountryCode {
+    /// Case-insensitive, fails if the code is not an ISO 3166-1 alpha-2 country.
+    pub fn new(code: &str) -> Result<Self, SteamErr> {
+        let code = code.trim().to_uppercase();
+        match code.as_bytes() {
+            [a, b] if rust_iso3166::from_alpha2(&code).is_some() => Ok(CountryCode([*a, *b])),
+            _ => Err(SteamErr::IncorrectCountryCode),
+        }
+    }
+
+    /// English name of the country.
+    pub fn name(&self) -> &'static str {
+        rust_iso3166::from_alpha2(self.as_ref())
+            .map(|c| c.name)
+            .unwrap_or_default()
+    }
+
+    /// Pricing region the store currently uses for this country.
+    /// Steam moves countries between regions from time to time,
+    /// so treat this as a prediction.
+    pub fn pricing_region(&self) -> PricingRegion {
+        use Currency::*;
+        use PricingRegion::{CisUsd, Currency as Local, LatamUsd, MenaUsd, SouthAsiaUsd};
+
+        match self.as_ref() {
+            "AT" | "BE" | "CY" | "DE" | "EE" | "ES" | "FI" | "FR" | "GR" | "IE" | "IT" | "LT"
+            | "LU" | "LV" | "MT" | "NL" | "PT" | "SI" | "SK" | "HR" | "BG" | "CZ" | "DK" | "HU"
+            | "RO" | "SE" | "AD" | "MC" | "SM" | "VA" | "ME" | "AL" | "BA" | "MK" | "RS" => {
+                Local(Eur)
+            }
+            "GB" | "IM" | "JE" | "GG" => Local(Gbp),
+            "CH" | "LI" => Local(Chf),
+            "NO" => Local(Nok),
+            "PL" => Local(Pln),
+            "RU" => Local(Rub),
+            "UA" => Local(Uah),
+            "KZ" => Local(Kzt),
+            "BR" => Local(Brl),
+            "MX" => Local(Mxn),
+            "CA" => Local(Cad),
+            "AU" => Local(Aud),
+            "NZ" => Local(Nzd),
+            "JP" => Local(Jpy),
+            "KR" => Local(Krw),
+            "CN" => Local(Cny),
+            "TW" => Local(Twd),
+            "HK" => Local(Hkd),
+            "SG" => Local(Sgd),
+            "MY" => Local(Myr),
+            "ID" => Local(Idr),
+            "PH" => Local(Php),
+            "TH" => Local(Thb),
+            "VN" => Local(Vnd),
+            "IN" => Local(Inr),
+            "CL" => Local(Clp),
+            "PE" => Local(Pen),
+            "CO" => Local(Cop),
+            "UY" => Local(Uyu),
+            "CR" => Local(Crc),
+            "ZA" => Local(Zar),
+            "IL" => Local(Ils),
+            "SA" => Local(Sar),
+            "AE" => Local(Aed),
+            "KW" => Local(Kwd),
+            "QA" => Local(Qar),
+            "AM" | "AZ" | "BY" | "GE" | "KG" | "MD" | "TJ" | "TM" | "UZ" => CisUsd,
+            "BD" | "BT" | "NP" | "PK" | "LK" => SouthAsiaUsd,
+            "AR" | "BO" | "BZ" | "EC" | "SV" | "GT" | "HN" | "NI" | "PA" | "PY" | "VE" => LatamUsd,
+            "TR" | "DZ" | "BH" | "EG" | "IQ" | "JO" | "LB" | "LY" | "MA" | "OM" | "TN" | "YE"
+            | "PS" => MenaUsd,
+            _ => Local(Usd),
+        }
+    }
+
+    /// Currency `AppPrice` is expected to come back in for this country.
+    pub fn default_currency(&self) -> Currency {
+        self.pricing_region().currency()
+    }
+}
+
+impl AsRef<str> for CountryCode {
+    fn as_ref(&self) -> &str {
+        // Only ASCII letters are ever stored.
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl fmt::Debug for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CountryCode").field(&self.as_ref()).finish()
+    }
+}
+
+impl FromStr for CountryCode {
+    type Err = SteamErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CountryCode::new(s)
+    }
+}
+
+impl TryFrom<String> for CountryCode {
+    type Error = SteamErr;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        CountryCode::new(&s)
+    }
+}
+
+impl TryFrom<&str> for CountryCode {
+    type Error = SteamErr;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        CountryCode::new(s)
+    }
+}
+
+impl From<CountryCode> for String {
+    fn from(c: CountryCode) -> Self {
+        c.as_ref().to_owned()
+    }
+}
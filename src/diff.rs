@@ -0,0 +1,307 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::app::AppDetails;
+use crate::package::PackageDetails;
+
+/// Fields identifying an element of a collection, e.g. a screenshot or a package app.
+const ID_FIELDS: &[&str] = &["id", "app_id", "dlc_id", "review_id"];
+
+/// How a field changed between two snapshots.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// One change between two versions of a store type.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct FieldChange {
+    /// Path of the field, e.g. `price_overview.final`, `screenshots[id=3]`
+    /// or `achievements.highlighted[0].name`.
+    pub path: String,
+    pub kind: ChangeKind,
+    /// Value before the change, `None` if added.
+    pub old: Option<Value>,
+    /// Value after the change, `None` if removed.
+    pub new: Option<Value>,
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => write!(f, "{}: {} -> {}", self.path, short(old), short(new)),
+            (None, Some(new)) => write!(f, "{}: added {}", self.path, short(new)),
+            (Some(old), None) => write!(f, "{}: removed {}", self.path, short(old)),
+            (None, None) => write!(f, "{}: changed", self.path),
+        }
+    }
+}
+
+/// Compact one-line form of a value, long strings are cut.
+fn short(value: &Value) -> String {
+    const MAX: usize = 80;
+
+    let text = match value {
+        Value::String(s) => format!("{:?}", s),
+        v => v.to_string(),
+    };
+    match text.char_indices().nth(MAX) {
+        Some((cut, _)) => format!("{}…", &text[..cut]),
+        None => text,
+    }
+}
+
+/// Structural diff of two serializable values, `old` to `new`.
+///
+/// Collections whose elements carry an id, like screenshots or categories,
+/// are matched by id; lists of plain values, like package ids, are compared
+/// as sets. Reordering alone is not reported.
+pub fn diff<T: Serialize>(old: &T, new: &T) -> Vec<FieldChange> {
+    let mut out = Vec::new();
+    match (serde_json::to_value(old), serde_json::to_value(new)) {
+        (Ok(old), Ok(new)) => diff_values(String::new(), &old, &new, &mut out),
+        // Store types always serialize, this only guards foreign ones.
+        _ => out.push(FieldChange {
+            path: String::new(),
+            kind: ChangeKind::Modified,
+            old: None,
+            new: None,
+        }),
+    }
+    out
+}
+
+impl AppDetails {
+    /// Changes from `old` to `self`.
+    pub fn diff(&self, old: &AppDetails) -> Vec<FieldChange> {
+        diff(old, self)
+    }
+}
+
+impl PackageDetails {
+    /// Changes from `old` to `self`.
+    pub fn diff(&self, old: &PackageDetails) -> Vec<FieldChange> {
+        diff(old, self)
+    }
+}
+
+fn diff_values(path: String, old: &Value, new: &Value, out: &mut Vec<FieldChange>) {
+    if old == new {
+        return;
+    }
+
+    match (old, new) {
+        (Value::Null, new) => out.push(change(path, None, Some(new))),
+        (old, Value::Null) => out.push(change(path, Some(old), None)),
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let path = join(&path, key);
+                match new.get(key) {
+                    Some(new_value) => diff_values(path, old_value, new_value, out),
+                    None if !old_value.is_null() => out.push(change(path, Some(old_value), None)),
+                    None => {}
+                }
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) && !new_value.is_null() {
+                    out.push(change(join(&path, key), None, Some(new_value)));
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => diff_arrays(path, old, new, out),
+        (old, new) => out.push(change(path, Some(old), Some(new))),
+    }
+}
+
+fn diff_arrays(path: String, old: &[Value], new: &[Value], out: &mut Vec<FieldChange>) {
+    if let Some(id) = ID_FIELDS
+        .iter()
+        .find(|id| keyed_by(old, id) && keyed_by(new, id))
+    {
+        let key = |v: &Value| v.get(id).cloned().unwrap_or_default();
+        for o in old {
+            let path = format!("{}[{}={}]", path, id, short(&key(o)));
+            match new.iter().find(|n| key(n) == key(o)) {
+                Some(n) => diff_values(path, o, n, out),
+                None => out.push(change(path, Some(o), None)),
+            }
+        }
+        for n in new {
+            if !old.iter().any(|o| key(o) == key(n)) {
+                let path = format!("{}[{}={}]", path, id, short(&key(n)));
+                out.push(change(path, None, Some(n)));
+            }
+        }
+    } else if old
+        .iter()
+        .chain(new)
+        .all(|v| !v.is_object() && !v.is_array())
+    {
+        for o in old.iter().filter(|o| !new.contains(o)) {
+            out.push(change(path.clone(), Some(o), None));
+        }
+        for n in new.iter().filter(|n| !old.contains(n)) {
+            out.push(change(path.clone(), None, Some(n)));
+        }
+    } else {
+        for i in 0..old.len().max(new.len()) {
+            let path = format!("{}[{}]", path, i);
+            match (old.get(i), new.get(i)) {
+                (Some(o), Some(n)) => diff_values(path, o, n, out),
+                (Some(o), None) => out.push(change(path, Some(o), None)),
+                (None, Some(n)) => out.push(change(path, None, Some(n))),
+                (None, None) => {}
+            }
+        }
+    }
+}
+
+/// Every element is an object with a distinct `id`.
+fn keyed_by(values: &[Value], id: &str) -> bool {
+    let keys: Vec<&Value> = values.iter().filter_map(|v| v.get(id)).collect();
+    keys.len() == values.len() && keys.iter().enumerate().all(|(i, k)| !keys[..i].contains(k))
+}
+
+fn change(path: String, old: Option<&Value>, new: Option<&Value>) -> FieldChange {
+    let kind = match (old, new) {
+        (None, _) => ChangeKind::Added,
+        (_, None) => ChangeKind::Removed,
+        _ => ChangeKind::Modified,
+    };
+
+    FieldChange {
+        path,
+        kind,
+        old: old.cloned(),
+        new: new.cloned(),
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn paths(changes: &[FieldChange]) -> Vec<(&str, ChangeKind)> {
+        changes.iter().map(|c| (c.path.as_str(), c.kind)).collect()
+    }
+
+    #[test]
+    fn arrays_matched_by_id() {
+        let old = json!({"screenshots": [
+            {"id": 1, "path_full": "a.jpg"},
+            {"id": 2, "path_full": "b.jpg"},
+            {"id": 3, "path_full": "c.jpg"},
+        ]});
+        let new = json!({"screenshots": [
+            {"id": 3, "path_full": "c.jpg"},
+            {"id": 1, "path_full": "a2.jpg"},
+            {"id": 4, "path_full": "d.jpg"},
+        ]});
+
+        let changes = diff(&old, &new);
+        assert_eq!(
+            paths(&changes),
+            [
+                ("screenshots[id=1].path_full", ChangeKind::Modified),
+                ("screenshots[id=2]", ChangeKind::Removed),
+                ("screenshots[id=4]", ChangeKind::Added),
+            ]
+        );
+        assert_eq!(changes[0].old, Some(json!("a.jpg")));
+        assert_eq!(changes[0].new, Some(json!("a2.jpg")));
+        assert_eq!(changes[2].new, Some(json!({"id": 4, "path_full": "d.jpg"})));
+    }
+
+    #[test]
+    fn plain_arrays_compared_as_sets() {
+        let old = json!({"packages": [10, 20, 30]});
+        let new = json!({"packages": [30, 10, 40]});
+
+        let changes = diff(&old, &new);
+        assert_eq!(
+            paths(&changes),
+            [
+                ("packages", ChangeKind::Removed),
+                ("packages", ChangeKind::Added),
+            ]
+        );
+        assert_eq!(changes[0].old, Some(json!(20)));
+        assert_eq!(changes[1].new, Some(json!(40)));
+
+        assert!(diff(&json!([1, 2, 3]), &json!([3, 2, 1])).is_empty());
+    }
+
+    #[test]
+    fn arrays_without_ids_by_index() {
+        let old = json!([{"name": "a"}, {"name": "b"}]);
+        let new = json!([{"name": "a"}, {"name": "c"}, {"name": "d"}]);
+
+        assert_eq!(
+            paths(&diff(&old, &new)),
+            [
+                ("[1].name", ChangeKind::Modified),
+                ("[2]", ChangeKind::Added),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_fields() {
+        let old = json!({
+            "name": "Game",
+            "price_overview": {"final": 1999, "discount_percent": 0},
+            "metacritic": {"score": 80},
+            "website": null,
+        });
+        let new = json!({
+            "name": "Game",
+            "price_overview": {"final": 999, "discount_percent": 50},
+            "ratings": {"pegi": "12"},
+            "website": "https://example.com",
+        });
+
+        let changes = diff(&old, &new);
+        assert_eq!(
+            paths(&changes),
+            [
+                ("metacritic", ChangeKind::Removed),
+                ("price_overview.discount_percent", ChangeKind::Modified),
+                ("price_overview.final", ChangeKind::Modified),
+                ("website", ChangeKind::Added),
+                ("ratings", ChangeKind::Added),
+            ]
+        );
+        assert_eq!(changes[2].to_string(), "price_overview.final: 1999 -> 999");
+        assert_eq!(
+            changes[0].to_string(),
+            r#"metacritic: removed {"score":80}"#
+        );
+        assert_eq!(
+            changes[3].to_string(),
+            r#"website: added "https://example.com""#
+        );
+    }
+
+    #[test]
+    fn null_keys_are_not_changes() {
+        let old = json!({"a": 1, "b": null});
+        let new = json!({"a": 1, "c": null});
+
+        assert!(diff(&old, &new).is_empty());
+    }
+}
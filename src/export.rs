@@ -0,0 +1,413 @@
+use std::io::Write;
+
+use futures::{pin_mut, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::review::{QuerySummary, Review};
+use crate::steam::SteamErr;
+
+/// One row per review, with the author flattened into `author_*` columns.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct ReviewRecord {
+    pub app_id: u64,
+    pub review_id: String,
+    pub author_id: String,
+    pub author_num_games_owned: u64,
+    pub author_num_reviews: u64,
+    /// Minutes
+    pub author_playtime_forever: u64,
+    /// Minutes
+    pub author_playtime_last_two_weeks: u64,
+    /// Minutes
+    pub author_playtime_at_review: u64,
+    /// Minutes
+    pub author_deck_playtime_at_review: Option<u64>,
+    /// Unix timestamp, 0 if never played
+    pub author_last_played: u64,
+    pub language: String,
+    pub review: String,
+    /// Unix timestamp
+    pub timestamp_created: u64,
+    /// Unix timestamp
+    pub timestamp_updated: u64,
+    pub received_for_free: bool,
+    pub steam_purchase: bool,
+    pub voted_up: bool,
+    pub votes_up: u64,
+    pub votes_funny: u64,
+    pub weighted_vote_score: f64,
+    pub written_during_early_access: bool,
+    pub comment_count: Option<u64>,
+    pub primarily_steam_deck: Option<bool>,
+    pub hidden_in_steam_china: Option<bool>,
+    pub steam_china_location: Option<String>,
+    pub developer_response: Option<String>,
+    /// Unix timestamp
+    pub timestamp_dev_responded: Option<u64>,
+}
+
+impl ReviewRecord {
+    pub fn new(app_id: u64, review: &Review) -> Self {
+        ReviewRecord {
+            app_id,
+            review_id: review.review_id.clone(),
+            author_id: review.author.user_id.clone(),
+            author_num_games_owned: review.author.num_games_owned,
+            author_num_reviews: review.author.num_reviews,
+            author_playtime_forever: review.author.playtime_forever,
+            author_playtime_last_two_weeks: review.author.playtime_last_two_weeks,
+            author_playtime_at_review: review.author.playtime_at_review,
+            author_deck_playtime_at_review: review.author.deck_playtime_at_review,
+            author_last_played: review.author.last_played,
+            language: review.language.clone(),
+            review: review.review.clone(),
+            timestamp_created: review.timestamp_created,
+            timestamp_updated: review.timestamp_updated,
+            received_for_free: review.received_for_free,
+            steam_purchase: review.steam_purchase,
+            voted_up: review.voted_up,
+            votes_up: review.votes_up,
+            votes_funny: review.votes_funny,
+            weighted_vote_score: review.weighted_vote_score.0,
+            written_during_early_access: review.written_during_early_access,
+            comment_count: review.comment_count.map(|c| c as u64),
+            primarily_steam_deck: review.primarily_steam_deck,
+            hidden_in_steam_china: review.hidden_in_steam_china,
+            steam_china_location: review.steam_china_location.clone(),
+            developer_response: review.developer_response.clone(),
+            timestamp_dev_responded: review.timestamp_dev_responded,
+        }
+    }
+}
+
+/// One row per review query.
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct SummaryRecord {
+    pub app_id: u64,
+    pub num_reviews: u32,
+    pub review_score: Option<u32>,
+    pub review_score_desc: Option<String>,
+    pub total_positive: Option<u32>,
+    pub total_negative: Option<u32>,
+    pub total_reviews: Option<u32>,
+}
+
+impl SummaryRecord {
+    pub fn new(app_id: u64, summary: &QuerySummary) -> Self {
+        SummaryRecord {
+            app_id,
+            num_reviews: summary.num_reviews,
+            review_score: summary.review_score,
+            review_score_desc: summary.review_score_desc.clone(),
+            total_positive: summary.total_positive,
+            total_negative: summary.total_negative,
+            total_reviews: summary.total_reviews,
+        }
+    }
+}
+
+/// Destination of exported records.
+pub trait RecordWriter<T> {
+    fn write(&mut self, record: &T) -> Result<(), SteamErr>;
+
+    /// Flushes buffered records and writes trailers, if the format has any.
+    fn finish(self) -> Result<(), SteamErr>;
+}
+
+/// Writes one JSON object per line.
+pub struct JsonLinesExporter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonLinesExporter<W> {
+    pub fn new(writer: W) -> Self {
+        JsonLinesExporter { writer }
+    }
+}
+
+impl<T: Serialize, W: Write> RecordWriter<T> for JsonLinesExporter<W> {
+    fn write(&mut self, record: &T) -> Result<(), SteamErr> {
+        serde_json::to_writer(&mut self.writer, record).map_err(SteamErr::JsonError)?;
+        self.writer.write_all(b"\n").map_err(SteamErr::IoError)
+    }
+
+    fn finish(mut self) -> Result<(), SteamErr> {
+        self.writer.flush().map_err(SteamErr::IoError)
+    }
+}
+
+/// Writes `reviews` of `app_id` one by one, returns how many were written.
+pub fn export_reviews<'a, I, E>(
+    reviews: I,
+    app_id: u64,
+    exporter: &mut E,
+) -> Result<usize, SteamErr>
+where
+    I: IntoIterator<Item = &'a Review>,
+    E: RecordWriter<ReviewRecord>,
+{
+    let mut count = 0;
+    for review in reviews {
+        exporter.write(&ReviewRecord::new(app_id, review))?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Writes reviews as they arrive, e.g. from [`Steam::reviews_stream`](crate::steam::Steam::reviews_stream),
+/// so they are never all held in memory. Stops at the first error.
+pub async fn export_review_stream<S, E>(
+    reviews: S,
+    app_id: u64,
+    exporter: &mut E,
+) -> Result<usize, SteamErr>
+where
+    S: Stream<Item = Result<Review, SteamErr>>,
+    E: RecordWriter<ReviewRecord>,
+{
+    pin_mut!(reviews);
+
+    let mut count = 0;
+    while let Some(review) = reviews.next().await {
+        exporter.write(&ReviewRecord::new(app_id, &review?))?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[cfg(feature = "csv")]
+pub use self::csv_export::CsvExporter;
+
+#[cfg(feature = "csv")]
+mod csv_export {
+    use std::io::Write;
+
+    use serde::Serialize;
+
+    use super::RecordWriter;
+    use crate::steam::SteamErr;
+
+    /// Writes records as CSV with a header row.
+    pub struct CsvExporter<W: Write> {
+        writer: csv::Writer<W>,
+    }
+
+    impl<W: Write> CsvExporter<W> {
+        pub fn new(writer: W) -> Self {
+            CsvExporter {
+                writer: csv::Writer::from_writer(writer),
+            }
+        }
+    }
+
+    impl<T: Serialize, W: Write> RecordWriter<T> for CsvExporter<W> {
+        fn write(&mut self, record: &T) -> Result<(), SteamErr> {
+            self.writer
+                .serialize(record)
+                .map_err(|e| SteamErr::IoError(e.into()))
+        }
+
+        fn finish(mut self) -> Result<(), SteamErr> {
+            self.writer.flush().map_err(SteamErr::IoError)
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+pub use self::parquet_export::{ArrowRecord, ParquetExporter};
+
+#[cfg(feature = "parquet")]
+mod parquet_export {
+    use std::io::Write;
+    use std::sync::Arc;
+
+    use arrow_array::{
+        ArrayRef, BooleanArray, Float64Array, RecordBatch, StringArray, UInt32Array, UInt64Array,
+    };
+    use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+    use parquet::arrow::ArrowWriter;
+
+    use super::{RecordWriter, ReviewRecord, SummaryRecord};
+    use crate::steam::SteamErr;
+
+    /// Rows written to Parquet per row group.
+    const BATCH_SIZE: usize = 8192;
+
+    /// Parquet and Arrow errors are reported as I/O errors,
+    /// so `SteamErr` doesn't change with the feature.
+    fn io_error<E: std::error::Error + Send + Sync + 'static>(e: E) -> SteamErr {
+        SteamErr::IoError(std::io::Error::other(e))
+    }
+
+    /// Record with a fixed Arrow schema.
+    pub trait ArrowRecord: Sized {
+        fn schema() -> SchemaRef;
+
+        fn to_batch(records: &[Self]) -> Result<RecordBatch, ArrowError>;
+    }
+
+    /// Writes records to a Parquet file in row groups of 8192 rows.
+    pub struct ParquetExporter<W: Write + Send, T: ArrowRecord> {
+        writer: ArrowWriter<W>,
+        buffer: Vec<T>,
+    }
+
+    impl<W: Write + Send, T: ArrowRecord> ParquetExporter<W, T> {
+        pub fn new(writer: W) -> Result<Self, SteamErr> {
+            let writer = ArrowWriter::try_new(writer, T::schema(), None).map_err(io_error)?;
+
+            Ok(ParquetExporter {
+                writer,
+                buffer: Vec::with_capacity(BATCH_SIZE),
+            })
+        }
+
+        fn flush_buffer(&mut self) -> Result<(), SteamErr> {
+            if self.buffer.is_empty() {
+                return Ok(());
+            }
+
+            let batch = T::to_batch(&self.buffer).map_err(io_error)?;
+            self.buffer.clear();
+            self.writer.write(&batch).map_err(io_error)
+        }
+    }
+
+    impl<W: Write + Send, T: ArrowRecord + Clone> RecordWriter<T> for ParquetExporter<W, T> {
+        fn write(&mut self, record: &T) -> Result<(), SteamErr> {
+            self.buffer.push(record.clone());
+            if self.buffer.len() >= BATCH_SIZE {
+                self.flush_buffer()?;
+            }
+            Ok(())
+        }
+
+        fn finish(mut self) -> Result<(), SteamErr> {
+            self.flush_buffer()?;
+            self.writer.close().map_err(io_error)?;
+            Ok(())
+        }
+    }
+
+    fn strings<T>(records: &[T], f: impl Fn(&T) -> Option<&str>) -> ArrayRef {
+        Arc::new(records.iter().map(f).collect::<StringArray>())
+    }
+
+    fn u64s<T>(records: &[T], f: impl Fn(&T) -> Option<u64>) -> ArrayRef {
+        Arc::new(records.iter().map(f).collect::<UInt64Array>())
+    }
+
+    fn u32s<T>(records: &[T], f: impl Fn(&T) -> Option<u32>) -> ArrayRef {
+        Arc::new(records.iter().map(f).collect::<UInt32Array>())
+    }
+
+    fn bools<T>(records: &[T], f: impl Fn(&T) -> Option<bool>) -> ArrayRef {
+        Arc::new(records.iter().map(f).collect::<BooleanArray>())
+    }
+
+    fn f64s<T>(records: &[T], f: impl Fn(&T) -> Option<f64>) -> ArrayRef {
+        Arc::new(records.iter().map(f).collect::<Float64Array>())
+    }
+
+    impl ArrowRecord for ReviewRecord {
+        fn schema() -> SchemaRef {
+            use DataType::*;
+
+            Arc::new(Schema::new(vec![
+                Field::new("app_id", UInt64, false),
+                Field::new("review_id", Utf8, false),
+                Field::new("author_id", Utf8, false),
+                Field::new("author_num_games_owned", UInt64, false),
+                Field::new("author_num_reviews", UInt64, false),
+                Field::new("author_playtime_forever", UInt64, false),
+                Field::new("author_playtime_last_two_weeks", UInt64, false),
+                Field::new("author_playtime_at_review", UInt64, false),
+                Field::new("author_deck_playtime_at_review", UInt64, true),
+                Field::new("author_last_played", UInt64, false),
+                Field::new("language", Utf8, false),
+                Field::new("review", Utf8, false),
+                Field::new("timestamp_created", UInt64, false),
+                Field::new("timestamp_updated", UInt64, false),
+                Field::new("received_for_free", Boolean, false),
+                Field::new("steam_purchase", Boolean, false),
+                Field::new("voted_up", Boolean, false),
+                Field::new("votes_up", UInt64, false),
+                Field::new("votes_funny", UInt64, false),
+                Field::new("weighted_vote_score", Float64, false),
+                Field::new("written_during_early_access", Boolean, false),
+                Field::new("comment_count", UInt64, true),
+                Field::new("primarily_steam_deck", Boolean, true),
+                Field::new("hidden_in_steam_china", Boolean, true),
+                Field::new("steam_china_location", Utf8, true),
+                Field::new("developer_response", Utf8, true),
+                Field::new("timestamp_dev_responded", UInt64, true),
+            ]))
+        }
+
+        fn to_batch(r: &[Self]) -> Result<RecordBatch, ArrowError> {
+            RecordBatch::try_new(
+                Self::schema(),
+                vec![
+                    u64s(r, |r| Some(r.app_id)),
+                    strings(r, |r| Some(&r.review_id)),
+                    strings(r, |r| Some(&r.author_id)),
+                    u64s(r, |r| Some(r.author_num_games_owned)),
+                    u64s(r, |r| Some(r.author_num_reviews)),
+                    u64s(r, |r| Some(r.author_playtime_forever)),
+                    u64s(r, |r| Some(r.author_playtime_last_two_weeks)),
+                    u64s(r, |r| Some(r.author_playtime_at_review)),
+                    u64s(r, |r| r.author_deck_playtime_at_review),
+                    u64s(r, |r| Some(r.author_last_played)),
+                    strings(r, |r| Some(&r.language)),
+                    strings(r, |r| Some(&r.review)),
+                    u64s(r, |r| Some(r.timestamp_created)),
+                    u64s(r, |r| Some(r.timestamp_updated)),
+                    bools(r, |r| Some(r.received_for_free)),
+                    bools(r, |r| Some(r.steam_purchase)),
+                    bools(r, |r| Some(r.voted_up)),
+                    u64s(r, |r| Some(r.votes_up)),
+                    u64s(r, |r| Some(r.votes_funny)),
+                    f64s(r, |r| Some(r.weighted_vote_score)),
+                    bools(r, |r| Some(r.written_during_early_access)),
+                    u64s(r, |r| r.comment_count),
+                    bools(r, |r| r.primarily_steam_deck),
+                    bools(r, |r| r.hidden_in_steam_china),
+                    strings(r, |r| r.steam_china_location.as_deref()),
+                    strings(r, |r| r.developer_response.as_deref()),
+                    u64s(r, |r| r.timestamp_dev_responded),
+                ],
+            )
+        }
+    }
+
+    impl ArrowRecord for SummaryRecord {
+        fn schema() -> SchemaRef {
+            use DataType::*;
+
+            Arc::new(Schema::new(vec![
+                Field::new("app_id", UInt64, false),
+                Field::new("num_reviews", UInt32, false),
+                Field::new("review_score", UInt32, true),
+                Field::new("review_score_desc", Utf8, true),
+                Field::new("total_positive", UInt32, true),
+                Field::new("total_negative", UInt32, true),
+                Field::new("total_reviews", UInt32, true),
+            ]))
+        }
+
+        fn to_batch(r: &[Self]) -> Result<RecordBatch, ArrowError> {
+            RecordBatch::try_new(
+                Self::schema(),
+                vec![
+                    u64s(r, |r| Some(r.app_id)),
+                    u32s(r, |r| Some(r.num_reviews)),
+                    u32s(r, |r| r.review_score),
+                    strings(r, |r| r.review_score_desc.as_deref()),
+                    u32s(r, |r| r.total_positive),
+                    u32s(r, |r| r.total_negative),
+                    u32s(r, |r| r.total_reviews),
+                ],
+            )
+        }
+    }
+}
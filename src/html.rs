@@ -0,0 +1,492 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::app::{AppDetails, ContentDescriptors};
+
+/// Output format of [`render`].
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum TextFormat {
+    Plain,
+    Markdown,
+}
+
+/// Store HTML converted to text.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Rendered {
+    pub body: String,
+    /// Image URLs in document order. Plain text omits images from `body`.
+    pub images: Vec<String>,
+}
+
+/// Converts Steam-flavored HTML to plain text or Markdown.
+///
+/// Scripts, styles and unknown tags are dropped, entities are decoded and
+/// `steamcommunity.com/linkfilter` redirects are replaced by their target.
+pub fn render(html: &str, format: TextFormat) -> Rendered {
+    let mut r = Renderer {
+        format,
+        out: String::with_capacity(html.len()),
+        images: Vec::new(),
+        lists: Vec::new(),
+        links: Vec::new(),
+        skip: 0,
+    };
+
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        r.text(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = tag_end(rest) else {
+            break;
+        };
+        r.tag(&rest[1..end - 1]);
+        rest = &rest[end..];
+    }
+    r.text(rest);
+
+    Rendered {
+        body: tidy(&r.out),
+        images: r.images,
+    }
+}
+
+impl AppDetails {
+    pub fn detailed_description_as(&self, format: TextFormat) -> Option<Rendered> {
+        self.detailed_description
+            .as_deref()
+            .map(|html| render(html, format))
+    }
+
+    pub fn about_the_game_as(&self, format: TextFormat) -> Option<Rendered> {
+        self.about_the_game
+            .as_deref()
+            .map(|html| render(html, format))
+    }
+
+    pub fn short_description_as(&self, format: TextFormat) -> Option<Rendered> {
+        self.short_description
+            .as_deref()
+            .map(|html| render(html, format))
+    }
+
+    pub fn legal_notice_as(&self, format: TextFormat) -> Option<Rendered> {
+        self.legal_notice
+            .as_deref()
+            .map(|html| render(html, format))
+    }
+}
+
+impl ContentDescriptors {
+    pub fn notes_as(&self, format: TextFormat) -> Option<Rendered> {
+        self.notes.as_deref().map(|html| render(html, format))
+    }
+}
+
+struct Renderer {
+    format: TextFormat,
+    out: String,
+    images: Vec<String>,
+    /// `None` for unordered lists, next item number for ordered ones.
+    lists: Vec<Option<u32>>,
+    /// Link target and where its text starts in `out`.
+    links: Vec<(Option<String>, usize)>,
+    /// Depth inside `script` or `style`.
+    skip: usize,
+}
+
+impl Renderer {
+    fn markdown(&self) -> bool {
+        self.format == TextFormat::Markdown
+    }
+
+    fn text(&mut self, raw: &str) {
+        if self.skip > 0 || raw.is_empty() {
+            return;
+        }
+
+        for c in decode_entities(raw).chars() {
+            if c.is_whitespace() {
+                if !self.out.is_empty() && !self.out.ends_with([' ', '\n']) {
+                    self.out.push(' ');
+                }
+                continue;
+            }
+            if self.markdown() && matches!(c, '*' | '_' | '`' | '[' | ']' | '#' | '\\') {
+                self.out.push('\\');
+            }
+            self.out.push(c);
+        }
+    }
+
+    fn line(&mut self) {
+        let trimmed = self.out.trim_end_matches(' ').len();
+        self.out.truncate(trimmed);
+        // Open links may have started on the trimmed spaces.
+        for (_, start) in &mut self.links {
+            *start = (*start).min(trimmed);
+        }
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    fn block(&mut self) {
+        self.line();
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    fn emphasis(&mut self, mark: &str) {
+        if self.markdown() {
+            self.out.push_str(mark);
+        }
+    }
+
+    fn tag(&mut self, inner: &str) {
+        let closing = inner.starts_with('/');
+        let inner = inner.trim_start_matches('/').trim_end_matches('/');
+        let name: String = inner
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        if matches!(name.as_str(), "script" | "style") {
+            if closing {
+                self.skip = self.skip.saturating_sub(1);
+            } else {
+                self.skip += 1;
+            }
+            return;
+        }
+        if self.skip > 0 {
+            return;
+        }
+
+        match (name.as_str(), closing) {
+            ("br", _) => self.line(),
+            ("p" | "div" | "table" | "tr", _) => self.block(),
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", false) => {
+                self.block();
+                if self.markdown() {
+                    let level = name[1..].parse().unwrap_or(2);
+                    self.out.push_str(&"#".repeat(level));
+                    self.out.push(' ');
+                }
+            }
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", true) => self.block(),
+            ("strong" | "b", _) => self.emphasis("**"),
+            ("em" | "i", _) => self.emphasis("*"),
+            ("strike" | "s" | "del", _) => self.emphasis("~~"),
+            ("hr", _) => {
+                self.block();
+                if self.markdown() {
+                    self.out.push_str("---");
+                    self.block();
+                }
+            }
+            ("blockquote", false) => {
+                self.block();
+                if self.markdown() {
+                    self.out.push_str("> ");
+                }
+            }
+            ("blockquote", true) => self.block(),
+            ("ul" | "ol", false) => {
+                if self.lists.is_empty() {
+                    self.block();
+                }
+                self.lists.push((name == "ol").then_some(1));
+            }
+            ("ul" | "ol", true) => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.block();
+                }
+            }
+            ("li", false) => {
+                self.line();
+                let depth = self.lists.len().max(1);
+                self.out.push_str(&"  ".repeat(depth - 1));
+                match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        self.out.push_str(&format!("{}. ", n));
+                        *n += 1;
+                    }
+                    _ => self.out.push_str("- "),
+                }
+            }
+            ("td" | "th", true) => self.out.push(' '),
+            ("img", false) => {
+                if let Some(src) = attribute(inner, "src") {
+                    if self.markdown() {
+                        self.out.push_str(&format!("![]({})", src));
+                    }
+                    self.images.push(src);
+                }
+            }
+            ("a", false) => {
+                let href = attribute(inner, "href").map(|h| unwrap_link_filter(&h));
+                if self.markdown() && href.is_some() {
+                    self.out.push('[');
+                }
+                self.links.push((href, self.out.len()));
+            }
+            ("a", true) => {
+                if let Some((Some(href), start)) = self.links.pop() {
+                    if self.markdown() {
+                        self.out.push_str(&format!("]({})", href));
+                    } else if self.out[start..].trim() != href {
+                        self.out.push_str(&format!(" ({})", href));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Index just past the `>` closing the tag at the start of `s`, honoring quotes.
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices().skip(1) {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+pub(crate) fn attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let mut from = 0;
+
+    while let Some(pos) = lower[from..].find(name) {
+        let start = from + pos;
+        from = start + name.len();
+
+        let boundary = lower[..start].ends_with(char::is_whitespace);
+        let rest = lower[from..].trim_start();
+        if !boundary || !rest.starts_with('=') {
+            continue;
+        }
+
+        let value = tag[tag.len() - rest.len() + 1..].trim_start();
+        let value = match value.chars().next() {
+            Some(q @ ('"' | '\'')) => value[1..].split(q).next().unwrap_or_default(),
+            _ => value.split(char::is_whitespace).next().unwrap_or_default(),
+        };
+        return Some(decode_entities(value));
+    }
+
+    None
+}
+
+/// Replaces `https://steamcommunity.com/linkfilter/?u=<target>` by the target.
+fn unwrap_link_filter(href: &str) -> String {
+    Url::parse(href)
+        .ok()
+        .filter(|u| u.path().starts_with("/linkfilter"))
+        .and_then(|u| {
+            u.query_pairs()
+                .find(|(k, _)| k == "u" || k == "url")
+                .map(|(_, v)| v.into_owned())
+        })
+        .unwrap_or_else(|| href.to_owned())
+}
+
+/// Trims lines and collapses runs of blank lines.
+fn tidy(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut blank = 0;
+
+    for line in text.lines().map(str::trim_end) {
+        if line.trim().is_empty() {
+            blank += 1;
+            continue;
+        }
+        if !out.is_empty() {
+            out.push_str(if blank > 0 { "\n\n" } else { "\n" });
+        }
+        blank = 0;
+        out.push_str(line);
+    }
+
+    out
+}
+
+/// Drops tags, keeping only the text.
+pub(crate) fn strip_tags(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+
+    decode_entities(&out)
+}
+
+/// Decodes the named entities Steam uses and numeric ones.
+pub(crate) fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_owned();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let decoded = rest.find(';').filter(|end| *end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                "ndash" => Some('–'),
+                "mdash" => Some('—'),
+                "hellip" => Some('…'),
+                "copy" => Some('©'),
+                "reg" => Some('®'),
+                "trade" => Some('™'),
+                e => e
+                    .strip_prefix("#x")
+                    .or_else(|| e.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| e.strip_prefix('#').map(str::parse))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(html: &str) -> String {
+        render(html, TextFormat::Plain).body
+    }
+
+    fn markdown(html: &str) -> String {
+        render(html, TextFormat::Markdown).body
+    }
+
+    #[test]
+    fn renders_blocks_and_lists() {
+        let html = "<h2>Features</h2><p>Fast &amp; fun</p><ul><li>One</li><li>Two</li></ul>\
+                    <ol><li>First</li><li>Second</li></ol>";
+
+        assert_eq!(
+            plain(html),
+            "Features\n\nFast & fun\n\n- One\n- Two\n\n1. First\n2. Second"
+        );
+        assert_eq!(
+            markdown(html),
+            "## Features\n\nFast & fun\n\n- One\n- Two\n\n1. First\n2. Second"
+        );
+    }
+
+    #[test]
+    fn renders_emphasis_and_escapes() {
+        assert_eq!(plain("<b>bold</b> <i>it</i>"), "bold it");
+        assert_eq!(markdown("<b>bold</b> <i>it</i> 2*3"), "**bold** *it* 2\\*3");
+    }
+
+    #[test]
+    fn renders_links_and_images() {
+        let html = "<a href=\"https://steamcommunity.com/linkfilter/?u=https%3A%2F%2Fexample.com\">site</a>\
+                    <img src=\"https://cdn.example.com/a.png\">";
+
+        assert_eq!(plain(html), "site (https://example.com)");
+        assert_eq!(
+            markdown(html),
+            "[site](https://example.com)![](https://cdn.example.com/a.png)"
+        );
+        assert_eq!(
+            render(html, TextFormat::Plain).images,
+            vec!["https://cdn.example.com/a.png".to_owned()]
+        );
+        assert_eq!(
+            plain("<a href=\"https://example.com\">https://example.com</a>"),
+            "https://example.com"
+        );
+    }
+
+    #[test]
+    fn link_over_trimmed_spaces() {
+        let html = "<td>x</td><td></td><td></td><a href=\"y\"><br></a>";
+
+        assert_eq!(plain(html), "x\n (y)");
+        assert!(markdown(html).ends_with("](y)"));
+        assert_eq!(
+            plain("<td>x</td><td></td><a href=\"y\"><br>é</a>"),
+            "x\né (y)"
+        );
+    }
+
+    #[test]
+    fn skips_scripts_and_comments() {
+        assert_eq!(
+            plain("a<script>var x = '<b>';</script><!-- note -->b<style>p {}</style>"),
+            "ab"
+        );
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(decode_entities("a &amp; b &lt;c&gt;"), "a & b <c>");
+        assert_eq!(decode_entities("&#169; &#x2122; &reg;"), "© ™ ®");
+        assert_eq!(
+            decode_entities("AT&T &unknown; &#xZZ;"),
+            "AT&T &unknown; &#xZZ;"
+        );
+        assert_eq!(decode_entities("tail &"), "tail &");
+    }
+
+    #[test]
+    fn strips_tags() {
+        assert_eq!(
+            strip_tags("English<strong>*</strong>, Fran&ccedil;ais &amp; more"),
+            "English*, Fran&ccedil;ais & more"
+        );
+        assert_eq!(strip_tags("<br>Intel® Core™</br>"), "Intel® Core™");
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+use serde_path_to_error::Segment;
+
+use crate::steam::SteamErr;
+
+/// Upper bound of fields dropped from one response before giving up.
+const MAX_REPAIRS: usize = 256;
+
+/// Fields of a response not known to this crate, kept as raw JSON.
+///
+/// Every response type flattens one into an `extra` field, so new fields
+/// Steam adds are not lost and survive a serialize round trip.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct Extra(pub Map<String, Value>);
+
+impl Hash for Extra {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (key, value) in &self.0 {
+            key.hash(state);
+            value.to_string().hash(state);
+        }
+    }
+}
+
+impl Deref for Extra {
+    type Target = Map<String, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Extra {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Field dropped by lenient parsing because it had an unexpected shape.
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ParseWarning {
+    /// Request the response came from, if fetched by [`Steam`](crate::steam::Steam).
+    pub url: Option<String>,
+    /// Path of the dropped value, e.g. `219990.data.metacritic`.
+    /// List indices count elements left after earlier drops.
+    pub path: String,
+    /// Why the value could not be read.
+    pub message: String,
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "dropped {}: {}", self.path, self.message)?;
+        if let Some(url) = &self.url {
+            write!(f, " ({})", url)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Step {
+    Key(String),
+    Index(usize),
+}
+
+/// Parses `json`, dropping values that don't fit `T` instead of failing.
+///
+/// A value that can't be read is replaced by `null`, so optional fields become
+/// `None`. If that isn't enough, the enclosing object is dropped the same way,
+/// and broken elements of lists are removed. Every drop is reported as a warning.
+/// Fails only if the document itself isn't JSON or can't be repaired.
+pub fn parse_lenient<T: DeserializeOwned>(json: &str) -> Result<(T, Vec<ParseWarning>), SteamErr> {
+    let mut value: Value = serde_json::from_str(json).map_err(SteamErr::JsonError)?;
+    let mut warnings = Vec::new();
+    let mut nulled: Vec<Vec<Step>> = Vec::new();
+
+    for _ in 0..MAX_REPAIRS {
+        let err = match serde_path_to_error::deserialize::<_, T>(&value) {
+            Ok(data) => return Ok((data, warnings)),
+            Err(err) => err,
+        };
+
+        let mut message = err.inner().to_string();
+        let mut path = steps(err.path());
+        while !path.is_empty() && (nulled.contains(&path) || !exists(&value, &path)) {
+            // Nulling wasn't enough, report the original problem for the parent instead.
+            let shown = display(&path);
+            if let Some(i) = warnings.iter().position(|w: &ParseWarning| w.path == shown) {
+                message = warnings.remove(i).message;
+            }
+            path.pop();
+        }
+        if path.is_empty() {
+            return Err(SteamErr::JsonError(err.into_inner()));
+        }
+
+        warnings.push(ParseWarning {
+            url: None,
+            path: display(&path),
+            message,
+        });
+        // Removing a list element always shrinks the document,
+        // only nulled fields need to be remembered.
+        if !drop_at(&mut value, &path) {
+            nulled.push(path);
+        }
+    }
+
+    serde_json::from_value(value)
+        .map(|data| (data, warnings))
+        .map_err(SteamErr::JsonError)
+}
+
+/// Known part of the error path, up to the first segment that can't be followed.
+fn steps(path: &serde_path_to_error::Path) -> Vec<Step> {
+    path.iter()
+        .map_while(|segment| match segment {
+            Segment::Map { key } => Some(Step::Key(key.to_owned())),
+            Segment::Seq { index } => Some(Step::Index(*index)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn get_mut<'a>(value: &'a mut Value, path: &[Step]) -> Option<&'a mut Value> {
+    path.iter().try_fold(value, |value, step| match step {
+        Step::Key(key) => value.get_mut(key.as_str()),
+        Step::Index(index) => value.get_mut(*index),
+    })
+}
+
+fn exists(value: &Value, path: &[Step]) -> bool {
+    path.iter()
+        .try_fold(value, |value, step| match step {
+            Step::Key(key) => value.get(key.as_str()),
+            Step::Index(index) => value.get(*index),
+        })
+        .is_some()
+}
+
+/// Removes a list element or nulls an object field, returns whether it was removed.
+fn drop_at(value: &mut Value, path: &[Step]) -> bool {
+    let Some((last, parent)) = path.split_last() else {
+        return false;
+    };
+
+    match (get_mut(value, parent), last) {
+        (Some(Value::Array(items)), Step::Index(index)) if *index < items.len() => {
+            items.remove(*index);
+            true
+        }
+        (Some(Value::Object(fields)), Step::Key(key)) => {
+            fields.insert(key.to_owned(), Value::Null);
+            false
+        }
+        _ => false,
+    }
+}
+
+fn display(path: &[Step]) -> String {
+    let mut out = String::new();
+    for step in path {
+        match step {
+            Step::Key(key) if out.is_empty() => out.push_str(key),
+            Step::Key(key) => {
+                out.push('.');
+                out.push_str(key);
+            }
+            Step::Index(index) => out.push_str(&format!("[{}]", index)),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Doc {
+        title: String,
+        score: Option<u32>,
+        inner: Option<Inner>,
+        #[serde(default)]
+        items: Vec<Item>,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Inner {
+        required: u32,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Item {
+        id: u64,
+    }
+
+    fn parse(json: &str) -> (Doc, Vec<String>) {
+        let (doc, warnings) = parse_lenient::<Doc>(json).unwrap();
+        (doc, warnings.into_iter().map(|w| w.path).collect())
+    }
+
+    #[test]
+    fn valid_document() {
+        let (doc, warnings) = parse(r#"{"title": "t", "score": 1, "inner": {"required": 2}}"#);
+        assert_eq!(doc.score, Some(1));
+        assert_eq!(doc.inner, Some(Inner { required: 2 }));
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn nulls_optional_field() {
+        let (doc, warnings) = parse(r#"{"title": "t", "score": "high"}"#);
+        assert_eq!(doc.title, "t");
+        assert_eq!(doc.score, None);
+        assert_eq!(warnings, ["score"]);
+    }
+
+    #[test]
+    fn drops_parent_of_required_field() {
+        let (doc, warnings) =
+            parse_lenient::<Doc>(r#"{"title": "t", "inner": {"required": "x"}}"#).unwrap();
+        assert_eq!(doc.inner, None);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "inner");
+        // The original problem is reported, not the null put in its place.
+        assert!(warnings[0].message.contains("string \"x\""));
+    }
+
+    #[test]
+    fn removes_list_elements() {
+        let (doc, warnings) =
+            parse(r#"{"title": "t", "items": [{"id": 1}, {"id": "x"}, {"id": "y"}, {"id": 4}]}"#);
+        assert_eq!(doc.items, [Item { id: 1 }, Item { id: 4 }]);
+        assert_eq!(warnings, ["items[1]", "items[1]"]);
+    }
+
+    #[test]
+    fn fails_when_unrepairable() {
+        assert!(parse_lenient::<Doc>(r#"{"title": 5}"#).is_err());
+        assert!(parse_lenient::<Doc>(r#"{"title": "#).is_err());
+    }
+}
@@ -29,24 +29,77 @@
 
 #![deny(warnings)]
 
+/// Statistics over fetched reviews.
+pub mod analytics;
 /// Apps info.
 pub mod app;
+/// Steam categories, genres and content descriptors.
+pub mod category;
+/// Country codes and pricing regions.
+pub mod country;
+/// Change detection between versions of store data.
+pub mod diff;
+/// Export of reviews to CSV, JSON Lines and Parquet.
+pub mod export;
+/// HTML conversion for store descriptions.
+pub mod html;
+/// Lenient parsing of responses.
+pub mod lenient;
+/// Downloading of screenshots, movies and capsule art.
+#[cfg(feature = "media")]
+pub mod media;
+/// Local HTTP server standing in for the store in tests.
+#[cfg(test)]
+mod mock;
+/// Currencies and money amounts.
+pub mod money;
 /// Apps package and DLC types.
 pub mod package;
+/// Parental filtering of apps.
+pub mod parental;
 /// Price and featured info.
 pub mod price;
+/// Release date parsing.
+pub mod release;
+/// System requirements parsing.
+pub mod requirements;
+/// Raw responses kept next to the typed data.
+pub mod response;
 /// User's reviews for apps.
 pub mod review;
+/// Versioned snapshots of store data.
+pub mod snapshot;
 /// API client
 pub mod steam;
+/// Incremental review sync with checkpoints.
+pub mod sync;
+/// Adaptive streaming trailers.
+pub mod trailer;
 /// Contains helpers for types.
 pub mod types;
 /// Prelude module, contains the most needed helpers from this library.
 pub mod prelude {
+    pub use crate::analytics::*;
     pub use crate::app::*;
+    pub use crate::category::*;
+    pub use crate::country::*;
+    pub use crate::diff::*;
+    pub use crate::export::*;
+    pub use crate::html::*;
+    pub use crate::lenient::*;
+    #[cfg(feature = "media")]
+    pub use crate::media::*;
+    pub use crate::money::*;
     pub use crate::package::*;
+    pub use crate::parental::*;
     pub use crate::price::*;
+    pub use crate::release::*;
+    pub use crate::requirements::*;
+    pub use crate::response::*;
     pub use crate::review::*;
+    pub use crate::snapshot::*;
     pub use crate::steam::*;
+    pub use crate::sync::*;
+    pub use crate::trailer::*;
     pub use crate::types::*;
 }
@@ -0,0 +1,444 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use futures::{stream, StreamExt};
+use reqwest::header::{HeaderMap, CONTENT_RANGE, ETAG, IF_RANGE, LAST_MODIFIED, RANGE};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use crate::app::AppDetails;
+use crate::steam::SteamErr;
+
+const MANIFEST: &str = "manifest.json";
+
+/// Kind of media asset of an application.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum AssetKind {
+    Header,
+    Capsule,
+    CapsuleV5,
+    Background,
+    BackgroundRaw,
+    Screenshot,
+    ScreenshotThumbnail,
+    Movie,
+    MovieThumbnail,
+}
+
+impl AsRef<str> for AssetKind {
+    fn as_ref(&self) -> &str {
+        use AssetKind::*;
+
+        match &self {
+            Header => "header",
+            Capsule => "capsule",
+            CapsuleV5 => "capsule_v5",
+            Background => "background",
+            BackgroundRaw => "background_raw",
+            Screenshot => "screenshot",
+            ScreenshotThumbnail => "screenshot_thumbnail",
+            Movie => "movie",
+            MovieThumbnail => "movie_thumbnail",
+        }
+    }
+}
+
+/// Downloadable media of an application.
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct MediaAsset {
+    pub app_id: u64,
+    pub kind: AssetKind,
+    /// Screenshot or movie id
+    pub id: Option<u64>,
+    pub url: String,
+}
+
+impl MediaAsset {
+    /// Path relative to the download directory,
+    /// e.g. `219990/screenshot_3.jpg` or `219990/header.jpg`.
+    pub fn file_name(&self) -> PathBuf {
+        let ext = url::Url::parse(&self.url)
+            .ok()
+            .and_then(|u| {
+                Path::new(u.path())
+                    .extension()
+                    .map(|e| e.to_string_lossy().to_lowercase())
+            })
+            .unwrap_or_else(|| "bin".to_owned());
+
+        let name = match self.id {
+            Some(id) => format!("{}_{}.{}", self.kind.as_ref(), id, ext),
+            None => format!("{}.{}", self.kind.as_ref(), ext),
+        };
+
+        PathBuf::from(self.app_id.to_string()).join(name)
+    }
+}
+
+impl AppDetails {
+    /// Media assets of the given kinds, all kinds if `kinds` is empty.
+    pub fn media_assets(&self, kinds: &[AssetKind]) -> Vec<MediaAsset> {
+        let mut out = Vec::new();
+        let mut push = |kind, id, url: Option<&str>| {
+            if let Some(url) = url {
+                if kinds.is_empty() || kinds.contains(&kind) {
+                    out.push(MediaAsset {
+                        app_id: self.app_id,
+                        kind,
+                        id,
+                        url: url.to_owned(),
+                    });
+                }
+            }
+        };
+
+        push(AssetKind::Header, None, self.header_image.as_deref());
+        push(AssetKind::Capsule, None, self.capsule_image.as_deref());
+        push(AssetKind::CapsuleV5, None, self.capsule_imagev5.as_deref());
+        push(AssetKind::Background, None, self.background.as_deref());
+        push(
+            AssetKind::BackgroundRaw,
+            None,
+            self.background_raw.as_deref(),
+        );
+
+        for s in self.screenshots.iter().flatten() {
+            push(AssetKind::Screenshot, Some(s.id), Some(&s.path_full[..]));
+            push(
+                AssetKind::ScreenshotThumbnail,
+                Some(s.id),
+                Some(&s.path_thumbnail[..]),
+            );
+        }
+
+        for m in self.movies.iter().flatten() {
+            // Streaming-only trailers have no single file to download.
+            push(AssetKind::Movie, Some(m.id), m.file_url());
+            push(
+                AssetKind::MovieThumbnail,
+                Some(m.id),
+                Some(&m.thumbnail[..]),
+            );
+        }
+
+        out
+    }
+}
+
+/// What happened to an asset.
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum DownloadStatus {
+    /// New or changed file was written.
+    Downloaded { bytes: u64, sha256: String },
+    /// Same URL and content as the last run, nothing was written.
+    Unchanged { sha256: String },
+}
+
+/// Result of downloading one asset.
+#[derive(Debug)]
+pub struct Download {
+    pub asset: MediaAsset,
+    pub path: PathBuf,
+    pub status: Result<DownloadStatus, SteamErr>,
+}
+
+/// What is known about a previously downloaded file.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+struct ManifestEntry {
+    url: String,
+    sha256: String,
+    #[serde(flatten)]
+    validators: Validators,
+}
+
+/// Headers identifying a version of a remote file.
+/// Also kept next to `.part` files, which are only resumed if one is known.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+struct Validators {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl Validators {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let get = |name| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_owned)
+        };
+
+        Validators {
+            etag: get(ETAG),
+            last_modified: get(LAST_MODIFIED),
+        }
+    }
+
+    /// Value for `If-Range`, the ETag if there is one.
+    fn if_range(&self) -> Option<&str> {
+        self.etag.as_deref().or(self.last_modified.as_deref())
+    }
+}
+
+/// Downloads media assets into a directory.
+///
+/// Files are named by [`MediaAsset::file_name`]. A `manifest.json` next to them
+/// records URL, ETag and SHA-256 of every file, so unchanged assets are skipped
+/// on later runs. Interrupted downloads are kept as `.part` files and resumed
+/// with a range request.
+pub struct MediaDownloader {
+    client: reqwest::Client,
+    dir: PathBuf,
+    concurrency: usize,
+}
+
+impl MediaDownloader {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        MediaDownloader {
+            client: reqwest::Client::new(),
+            dir: dir.as_ref().to_path_buf(),
+            concurrency: 4,
+        }
+    }
+
+    /// Maximum number of simultaneous downloads, 4 by default.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Downloads `assets`, returning one result per asset in completion order.
+    pub async fn download<I>(&self, assets: I) -> Result<Vec<Download>, SteamErr>
+    where
+        I: IntoIterator<Item = MediaAsset>,
+    {
+        fs::create_dir_all(&self.dir)
+            .await
+            .map_err(SteamErr::IoError)?;
+
+        let mut manifest = self.read_manifest().await;
+
+        let results: Vec<(Download, Option<ManifestEntry>)> = stream::iter(assets)
+            .map(|asset| {
+                let previous = manifest.get(&manifest_key(&asset)).cloned();
+                async move {
+                    let path = self.dir.join(asset.file_name());
+                    let res = self.fetch(&asset, &path, previous).await;
+                    let (status, entry) = match res {
+                        Ok((status, entry)) => (Ok(status), Some(entry)),
+                        Err(e) => (Err(e), None),
+                    };
+                    (
+                        Download {
+                            asset,
+                            path,
+                            status,
+                        },
+                        entry,
+                    )
+                }
+            })
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+
+        let mut out = Vec::with_capacity(results.len());
+        for (download, entry) in results {
+            if let Some(entry) = entry {
+                manifest.insert(manifest_key(&download.asset), entry);
+            }
+            out.push(download);
+        }
+
+        self.write_manifest(&manifest).await?;
+
+        Ok(out)
+    }
+
+    async fn fetch(
+        &self,
+        asset: &MediaAsset,
+        path: &Path,
+        previous: Option<ManifestEntry>,
+    ) -> Result<(DownloadStatus, ManifestEntry), SteamErr> {
+        if let Some(previous) = &previous {
+            if previous.url == asset.url
+                && hash_file(path).await.ok() == Some(previous.sha256.clone())
+            {
+                return Ok((
+                    DownloadStatus::Unchanged {
+                        sha256: previous.sha256.clone(),
+                    },
+                    previous.clone(),
+                ));
+            }
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(SteamErr::IoError)?;
+        }
+
+        let part = path.with_extension(format!(
+            "{}.part",
+            path.extension().unwrap_or_default().to_string_lossy()
+        ));
+        let part_meta = part.with_extension("part.json");
+
+        let mut offset = fs::metadata(&part).await.map(|m| m.len()).unwrap_or(0);
+        let stored: Option<Validators> = fs::read(&part_meta)
+            .await
+            .ok()
+            .and_then(|data| serde_json::from_slice(&data).ok());
+        let if_range = stored.as_ref().and_then(Validators::if_range);
+        if offset > 0 && if_range.is_none() {
+            // Without a validator a changed file would be spliced onto stale bytes.
+            offset = 0;
+        }
+
+        let mut res = self.get(&asset.url, offset, if_range).await?;
+        if res.status() == StatusCode::RANGE_NOT_SATISFIABLE
+            && complete_length(&res) != Some(offset)
+        {
+            // The part doesn't fit the remote file, start over.
+            offset = 0;
+            res = self.get(&asset.url, offset, None).await?;
+        }
+
+        // 416 for a part as long as the remote file: it was already complete.
+        let validators = if offset > 0 && res.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            stored.unwrap_or_default()
+        } else {
+            let res = res.error_for_status().map_err(SteamErr::RequestError)?;
+            let resumed = res.status() == StatusCode::PARTIAL_CONTENT;
+            let validators = if resumed {
+                stored.unwrap_or_default()
+            } else {
+                Validators::from_headers(res.headers())
+            };
+
+            if !resumed {
+                let data = serde_json::to_vec(&validators).map_err(SteamErr::JsonError)?;
+                fs::write(&part_meta, data)
+                    .await
+                    .map_err(SteamErr::IoError)?;
+            }
+
+            let mut file = fs::OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(resumed)
+                .truncate(!resumed)
+                .open(&part)
+                .await
+                .map_err(SteamErr::IoError)?;
+
+            let mut body = res.bytes_stream();
+            while let Some(chunk) = body.next().await {
+                let chunk = chunk.map_err(SteamErr::RequestError)?;
+                file.write_all(&chunk).await.map_err(SteamErr::IoError)?;
+            }
+            file.flush().await.map_err(SteamErr::IoError)?;
+
+            validators
+        };
+        // The part is complete, a missing sidecar is harmless.
+        let _ = fs::remove_file(&part_meta).await;
+
+        let sha256 = hash_file(&part).await.map_err(SteamErr::IoError)?;
+        let entry = ManifestEntry {
+            url: asset.url.clone(),
+            sha256: sha256.clone(),
+            validators,
+        };
+
+        if hash_file(path).await.ok().as_ref() == Some(&sha256) {
+            fs::remove_file(&part).await.map_err(SteamErr::IoError)?;
+            return Ok((DownloadStatus::Unchanged { sha256 }, entry));
+        }
+
+        let bytes = fs::metadata(&part).await.map_err(SteamErr::IoError)?.len();
+        fs::rename(&part, path).await.map_err(SteamErr::IoError)?;
+
+        Ok((DownloadStatus::Downloaded { bytes, sha256 }, entry))
+    }
+
+    /// GET `url`, from `offset` on if the part can be validated by `if_range`.
+    async fn get(
+        &self,
+        url: &str,
+        offset: u64,
+        if_range: Option<&str>,
+    ) -> Result<reqwest::Response, SteamErr> {
+        let mut req = self.client.get(url);
+        if let (true, Some(if_range)) = (offset > 0, if_range) {
+            req = req
+                .header(RANGE, format!("bytes={}-", offset))
+                .header(IF_RANGE, if_range);
+        }
+
+        req.send().await.map_err(SteamErr::RequestError)
+    }
+
+    async fn read_manifest(&self) -> HashMap<String, ManifestEntry> {
+        match fs::read(self.dir.join(MANIFEST)).await {
+            Ok(data) => serde_json::from_slice(&data).unwrap_or_default(),
+            Err(_) => HashMap::new(),
+        }
+    }
+
+    async fn write_manifest(
+        &self,
+        manifest: &HashMap<String, ManifestEntry>,
+    ) -> Result<(), SteamErr> {
+        let data = serde_json::to_vec_pretty(manifest).map_err(SteamErr::JsonError)?;
+        fs::write(self.dir.join(MANIFEST), data)
+            .await
+            .map_err(SteamErr::IoError)
+    }
+}
+
+/// Length of the remote file from a 416 response, e.g. `bytes */1234`.
+fn complete_length(res: &reqwest::Response) -> Option<u64> {
+    res.headers()
+        .get(CONTENT_RANGE)?
+        .to_str()
+        .ok()?
+        .strip_prefix("bytes */")?
+        .trim()
+        .parse()
+        .ok()
+}
+
+fn manifest_key(asset: &MediaAsset) -> String {
+    asset.file_name().to_string_lossy().replace('\\', "/")
+}
+
+async fn hash_file(path: &Path) -> std::io::Result<String> {
+    let mut file = fs::File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0; 64 * 1024];
+
+    loop {
+        let n = file.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use url::Url;
+
+/// Serves `handler(path_and_query)` as JSON on a local port, returns its URL.
+pub(crate) async fn serve<F>(handler: F) -> Url
+where
+    F: Fn(&str) -> String + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+    let handler = Arc::new(handler);
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let handler = handler.clone();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match socket.read(&mut buf).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                let request = String::from_utf8_lossy(&request);
+                let target = request.split(' ').nth(1).unwrap_or_default();
+                let body = handler(target);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                     content-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+
+    url
+}
+
+/// An `appreviews` review with the given id and Unix timestamps.
+pub(crate) fn review(id: &str, created: u64, updated: u64) -> serde_json::Value {
+    serde_json::json!({
+        "recommendationid": id,
+        "author": {
+            "steamid": "76561197960287930",
+            "num_games_owned": 10,
+            "num_reviews": 1,
+            "playtime_forever": 120,
+            "playtime_last_two_weeks": 0,
+            "playtime_at_review": 60,
+            "last_played": updated,
+        },
+        "language": "english",
+        "review": "Fine.",
+        "timestamp_created": created,
+        "timestamp_updated": updated,
+        "received_for_free": false,
+        "steam_purchase": true,
+        "voted_up": true,
+        "votes_up": 0,
+        "votes_funny": 0,
+        "weighted_vote_score": "0.5",
+        "written_during_early_access": false,
+    })
+}
+
+/// An `appreviews` page holding `reviews`, pointing to `cursor`.
+pub(crate) fn reviews_page(reviews: Vec<serde_json::Value>, cursor: &str) -> String {
+    serde_json::json!({
+        "success": 1,
+        "query_summary": { "num_reviews": reviews.len() },
+        "reviews": reviews,
+        "cursor": cursor,
+    })
+    .to_string()
+}
@@ -0,0 +1,474 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::steam::SteamErr;
+
+/// Currencies used by the Steam store, denoted by ISO 4217 code.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub enum Currency {
+    Usd,
+    Gbp,
+    Eur,
+    Chf,
+    Rub,
+    Pln,
+    Brl,
+    Jpy,
+    Nok,
+    Idr,
+    Myr,
+    Php,
+    Sgd,
+    Thb,
+    Vnd,
+    Krw,
+    /// No longer offered by Steam, kept for older responses.
+    Try,
+    Uah,
+    Mxn,
+    Cad,
+    Aud,
+    Nzd,
+    Cny,
+    Inr,
+    Clp,
+    Pen,
+    Cop,
+    Zar,
+    Hkd,
+    Twd,
+    Sar,
+    Aed,
+    /// No longer offered by Steam, kept for older responses.
+    Ars,
+    Ils,
+    Kzt,
+    Kwd,
+    Qar,
+    Crc,
+    Uyu,
+    /// Any code not known to this crate, as uppercase ASCII letters.
+    Unknown([u8; 3]),
+}
+
+/// How an amount is laid out when formatted.
+struct Layout {
+    symbol: &'static str,
+    prefix: bool,
+    thousands: &'static str,
+    decimal: &'static str,
+}
+
+impl Currency {
+    /// All currencies supported by the Steam store.
+    pub const ALL: [Currency; 39] = {
+        use Currency::*;
+        [
+            Usd, Gbp, Eur, Chf, Rub, Pln, Brl, Jpy, Nok, Idr, Myr, Php, Sgd, Thb, Vnd, Krw, Try,
+            Uah, Mxn, Cad, Aud, Nzd, Cny, Inr, Clp, Pen, Cop, Zar, Hkd, Twd, Sar, Aed, Ars, Ils,
+            Kzt, Kwd, Qar, Crc, Uyu,
+        ]
+    };
+
+    /// Known currency by code, or `Unknown` for any other three-letter code.
+    /// Case-insensitive.
+    pub fn new(code: &str) -> Result<Self, SteamErr> {
+        let code = code.trim().to_ascii_uppercase();
+        if let Some(known) = Currency::ALL.into_iter().find(|c| c.as_ref() == code) {
+            return Ok(known);
+        }
+        match code.as_bytes() {
+            [a, b, c] if code.bytes().all(|b| b.is_ascii_uppercase()) => {
+                Ok(Currency::Unknown([*a, *b, *c]))
+            }
+            _ => Err(SteamErr::UnknownCurrency(code)),
+        }
+    }
+
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Currency::Unknown(_))
+    }
+
+    /// Number of decimal places the store shows for this currency,
+    /// e.g. 2 for USD and 0 for JPY or KRW.
+    pub fn exponent(&self) -> u32 {
+        use Currency::*;
+
+        match self {
+            Jpy | Krw | Idr | Vnd | Clp | Cop | Twd | Inr | Kzt | Uah | Rub | Crc | Uyu => 0,
+            _ => 2,
+        }
+    }
+
+    /// Symbol used by the store, e.g. `$`, `€`, `CDN$`.
+    /// Unknown currencies use their code.
+    pub fn symbol(&self) -> &str {
+        match self {
+            Currency::Unknown(_) => self.as_ref(),
+            _ => self.layout().symbol.trim(),
+        }
+    }
+
+    fn layout(&self) -> Layout {
+        use Currency::*;
+
+        let (symbol, prefix, thousands, decimal) = match self {
+            Usd => ("$", true, ",", "."),
+            Gbp => ("£", true, ",", "."),
+            Eur => ("€", false, ".", ","),
+            Chf => ("CHF ", true, "'", "."),
+            Rub => (" pуб.", false, " ", ","),
+            Pln => ("zł", false, " ", ","),
+            Brl => ("R$ ", true, ".", ","),
+            Jpy => ("¥ ", true, ",", "."),
+            Nok => (" kr", false, " ", ","),
+            Idr => ("Rp ", true, " ", ","),
+            Myr => ("RM", true, ",", "."),
+            Php => ("₱", true, ",", "."),
+            Sgd => ("S$", true, ",", "."),
+            Thb => ("฿", true, ",", "."),
+            Vnd => ("₫", false, ".", ","),
+            Krw => ("₩ ", true, ",", "."),
+            Try => ("₺", false, ".", ","),
+            Uah => ("₴", false, " ", ","),
+            Mxn => ("Mex$ ", true, ",", "."),
+            Cad => ("CDN$ ", true, ",", "."),
+            Aud => ("A$ ", true, ",", "."),
+            Nzd => ("NZ$ ", true, ",", "."),
+            Cny => ("¥ ", true, ",", "."),
+            Inr => ("₹ ", true, ",", "."),
+            Clp => ("CLP$ ", true, ".", ","),
+            Pen => ("S/.", true, ",", "."),
+            Cop => ("COL$ ", true, ".", ","),
+            Zar => ("R ", true, " ", "."),
+            Hkd => ("HK$ ", true, ",", "."),
+            Twd => ("NT$ ", true, ",", "."),
+            Sar => (" SR", false, ",", "."),
+            Aed => (" AED", false, ",", "."),
+            Ars => ("ARS$ ", true, ".", ","),
+            Ils => ("₪", true, ",", "."),
+            Kzt => ("₸", false, " ", ","),
+            Kwd => (" KD", false, ",", "."),
+            Qar => (" QR", false, ",", "."),
+            Crc => ("₡", true, ".", ","),
+            Uyu => ("$U", true, ".", ","),
+            // Display writes the code instead
+            Unknown(_) => ("", false, ",", "."),
+        };
+
+        Layout {
+            symbol,
+            prefix,
+            thousands,
+            decimal,
+        }
+    }
+}
+
+impl AsRef<str> for Currency {
+    fn as_ref(&self) -> &str {
+        use Currency::*;
+
+        match &self {
+            Usd => "USD",
+            Gbp => "GBP",
+            Eur => "EUR",
+            Chf => "CHF",
+            Rub => "RUB",
+            Pln => "PLN",
+            Brl => "BRL",
+            Jpy => "JPY",
+            Nok => "NOK",
+            Idr => "IDR",
+            Myr => "MYR",
+            Php => "PHP",
+            Sgd => "SGD",
+            Thb => "THB",
+            Vnd => "VND",
+            Krw => "KRW",
+            Try => "TRY",
+            Uah => "UAH",
+            Mxn => "MXN",
+            Cad => "CAD",
+            Aud => "AUD",
+            Nzd => "NZD",
+            Cny => "CNY",
+            Inr => "INR",
+            Clp => "CLP",
+            Pen => "PEN",
+            Cop => "COP",
+            Zar => "ZAR",
+            Hkd => "HKD",
+            Twd => "TWD",
+            Sar => "SAR",
+            Aed => "AED",
+            Ars => "ARS",
+            Ils => "ILS",
+            Kzt => "KZT",
+            Kwd => "KWD",
+            Qar => "QAR",
+            Crc => "CRC",
+            Uyu => "UYU",
+            // Only ASCII letters are ever stored.
+            Unknown(code) => std::str::from_utf8(code).unwrap_or_default(),
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = SteamErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Currency::new(s)
+    }
+}
+
+impl TryFrom<String> for Currency {
+    type Error = SteamErr;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Currency::new(&s)
+    }
+}
+
+impl From<Currency> for String {
+    fn from(c: Currency) -> Self {
+        c.as_ref().to_owned()
+    }
+}
+
+/// An amount of money in a single currency.
+///
+/// Steam reports every price in hundredths of the major unit, whatever
+/// the currency, so ¥ 3,280 comes back as `328000`. `Money` keeps that
+/// representation and uses [`Currency::exponent`] only when converting
+/// to minor units or formatting.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Money {
+    amount: i64,
+    currency: Currency,
+}
+
+impl Money {
+    /// Amount as returned by the Steam store, in hundredths of the major unit.
+    pub fn new(amount: i64, currency: Currency) -> Self {
+        Money { amount, currency }
+    }
+
+    /// Amount in the currency's minor units, e.g. yen for JPY and cents for USD.
+    pub fn from_minor_units(units: i64, currency: Currency) -> Self {
+        let amount = match currency.exponent() {
+            e if e >= 2 => units / 10_i64.pow(e - 2),
+            e => units * 10_i64.pow(2 - e),
+        };
+        Money { amount, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Self {
+        Money::new(0, currency)
+    }
+
+    /// Amount in hundredths of the major unit, as Steam reports it.
+    pub fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Amount in the currency's minor units, rounded half away from zero.
+    pub fn minor_units(&self) -> i64 {
+        match self.currency.exponent() {
+            e if e >= 2 => self.amount * 10_i64.pow(e - 2),
+            e => {
+                let div = 10_i64.pow(2 - e);
+                let rem = self.amount % div;
+                let mut units = self.amount / div;
+                if rem.abs() * 2 >= div {
+                    units += self.amount.signum();
+                }
+                units
+            }
+        }
+    }
+
+    /// Amount in major units, for display or statistics only.
+    pub fn to_major(&self) -> f64 {
+        self.amount as f64 / 100.0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// `None` if currencies differ or the sum overflows.
+    pub fn checked_add(self, rhs: Money) -> Option<Money> {
+        if self.currency != rhs.currency {
+            return None;
+        }
+        self.amount
+            .checked_add(rhs.amount)
+            .map(|amount| Money::new(amount, self.currency))
+    }
+
+    /// `None` if currencies differ or the difference overflows.
+    pub fn checked_sub(self, rhs: Money) -> Option<Money> {
+        if self.currency != rhs.currency {
+            return None;
+        }
+        self.amount
+            .checked_sub(rhs.amount)
+            .map(|amount| Money::new(amount, self.currency))
+    }
+
+    /// Applies a percentage discount, rounding to the nearest unit.
+    pub fn discounted(&self, percent: u8) -> Money {
+        let percent = i64::from(percent.min(100));
+        let amount = (self.amount * (100 - percent) + 50) / 100;
+        Money::new(amount, self.currency)
+    }
+
+    /// `None` if the product overflows.
+    pub fn checked_mul(self, rhs: i64) -> Option<Money> {
+        self.amount
+            .checked_mul(rhs)
+            .map(|amount| Money::new(amount, self.currency))
+    }
+
+    /// `None` if the amount is `i64::MIN`.
+    pub fn checked_neg(self) -> Option<Money> {
+        self.amount
+            .checked_neg()
+            .map(|amount| Money::new(amount, self.currency))
+    }
+}
+
+/// Amounts are only comparable within one currency.
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.currency != other.currency {
+            return None;
+        }
+        self.amount.partial_cmp(&other.amount)
+    }
+}
+
+/// Formats the amount the way the store does for its currency,
+/// e.g. `$1,234.56`, `1.234,56€` or `¥ 3,280`.
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let layout = self.currency.layout();
+        let exponent = self.currency.exponent();
+        let units = self.minor_units().unsigned_abs();
+        let scale = 10_u64.pow(exponent);
+
+        let digits = (units / scale).to_string();
+        let mut major = String::with_capacity(digits.len() * 2);
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                major.push_str(layout.thousands);
+            }
+            major.push(c);
+        }
+
+        let mut number = major;
+        if exponent > 0 {
+            number.push_str(layout.decimal);
+            number.push_str(&format!(
+                "{:0width$}",
+                units % scale,
+                width = exponent as usize
+            ));
+        }
+
+        let sign = if self.amount < 0 { "-" } else { "" };
+
+        if let Currency::Unknown(_) = self.currency {
+            write!(f, "{}{} {}", sign, number, self.currency)
+        } else if layout.prefix {
+            write!(f, "{}{}{}", sign, layout.symbol, number)
+        } else {
+            write!(f, "{}{}{}", sign, number, layout.symbol)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exponents() {
+        assert_eq!(Currency::Usd.exponent(), 2);
+        assert_eq!(Currency::Eur.exponent(), 2);
+        assert_eq!(Currency::Jpy.exponent(), 0);
+        assert_eq!(Currency::Krw.exponent(), 0);
+        assert_eq!(Currency::new("XAF").unwrap().exponent(), 2);
+    }
+
+    #[test]
+    fn minor_units() {
+        assert_eq!(Money::new(1999, Currency::Usd).minor_units(), 1999);
+        assert_eq!(Money::new(328000, Currency::Jpy).minor_units(), 3280);
+        assert_eq!(Money::new(328050, Currency::Jpy).minor_units(), 3281);
+        assert_eq!(Money::new(328049, Currency::Jpy).minor_units(), 3280);
+        assert_eq!(Money::new(-328050, Currency::Jpy).minor_units(), -3281);
+        assert_eq!(
+            Money::from_minor_units(3280, Currency::Jpy).amount(),
+            328000
+        );
+        assert_eq!(Money::from_minor_units(1999, Currency::Usd).amount(), 1999);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Money::new(328000, Currency::Jpy).to_string(), "¥ 3,280");
+        assert_eq!(
+            Money::new(165_000_000, Currency::Krw).to_string(),
+            "₩ 1,650,000"
+        );
+        assert_eq!(Money::new(123456, Currency::Eur).to_string(), "1.234,56€");
+        assert_eq!(Money::new(-5, Currency::Eur).to_string(), "-0,05€");
+        assert_eq!(Money::new(123456, Currency::Usd).to_string(), "$1,234.56");
+        let xaf = Currency::new("xaf").unwrap();
+        assert_eq!(Money::new(123456, xaf).to_string(), "1,234.56 XAF");
+    }
+
+    #[test]
+    fn unknown_currency_serde() {
+        let xaf = Currency::new("XAF").unwrap();
+        assert!(!xaf.is_known());
+        assert_eq!(serde_json::to_string(&xaf).unwrap(), r#""XAF""#);
+        assert_eq!(serde_json::from_str::<Currency>(r#""XAF""#).unwrap(), xaf);
+        assert_eq!(
+            serde_json::from_str::<Currency>(r#""eur""#).unwrap(),
+            Currency::Eur
+        );
+        assert!(serde_json::from_str::<Currency>(r#""EURO""#).is_err());
+
+        let money = Money::new(1500, xaf);
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), money);
+    }
+
+    #[test]
+    fn checked_ops() {
+        let usd = Money::new(1999, Currency::Usd);
+        assert_eq!(usd.checked_mul(3), Some(Money::new(5997, Currency::Usd)));
+        assert_eq!(usd.checked_mul(i64::MAX), None);
+        assert_eq!(usd.checked_neg(), Some(Money::new(-1999, Currency::Usd)));
+        assert_eq!(Money::new(i64::MIN, Currency::Usd).checked_neg(), None);
+        assert_eq!(usd.checked_add(Money::new(1, Currency::Eur)), None);
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    lenient::Extra,
     price::{PackagePrice, Price},
     types::{Platforms, ReleaseDate},
 };
@@ -11,9 +12,9 @@ pub(crate) struct PackageData {
     pub data: Option<PackageDetails>,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct PackageDetails {
-    #[serde(skip_deserializing)]
+    #[serde(default)]
     pub pkg_id: u64,
     pub name: String,
     pub page_image: String,
@@ -24,32 +25,40 @@ pub struct PackageDetails {
     pub platforms: Platforms,
     pub controller: Controller,
     pub release_date: ReleaseDate,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct PackageApp {
-    #[serde(rename(deserialize = "id"))]
+    #[serde(rename(deserialize = "id"), alias = "app_id")]
     pub app_id: u64,
     pub name: String,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Controller {
     pub full_gamepad: bool,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct DlcData {
     pub(crate) status: i8,
-    #[serde(rename(deserialize = "appid"))]
+    #[serde(rename(deserialize = "appid"), alias = "app_id")]
     pub app_id: String,
     pub name: String,
     pub dlc: Option<Vec<DlcDetails>>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct DlcDetails {
-    #[serde(rename(deserialize = "id"))]
+    #[serde(rename(deserialize = "id"), alias = "dlc_id")]
     pub dlc_id: u64,
     pub name: String,
     pub header_image: String,
@@ -57,4 +66,6 @@ pub struct DlcDetails {
     pub platforms: Platforms,
     pub release_date: ReleaseDate,
     pub controller_support: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
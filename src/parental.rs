@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppDetails;
+use crate::category::ContentDescriptor;
+
+/// Decides whether an app may be shown, based on its `required_age`
+/// and content descriptors.
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ParentalPolicy {
+    /// Highest `required_age` allowed, `None` for no limit.
+    pub max_required_age: Option<u8>,
+    /// Descriptors that make an app disallowed.
+    pub blocked: Vec<ContentDescriptor>,
+    /// Whether descriptors unknown to this crate are allowed.
+    pub allow_unknown: bool,
+}
+
+/// Reason an app is not allowed by a [`ParentalPolicy`].
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum PolicyViolation {
+    RequiredAge(u8),
+    Descriptor(ContentDescriptor),
+}
+
+impl Default for ParentalPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParentalPolicy {
+    /// Allows everything.
+    pub fn new() -> Self {
+        ParentalPolicy {
+            max_required_age: None,
+            blocked: Vec::new(),
+            allow_unknown: true,
+        }
+    }
+
+    /// No age gate and no mature content of any kind.
+    pub fn family_friendly() -> Self {
+        ParentalPolicy {
+            max_required_age: Some(0),
+            blocked: ContentDescriptor::ALL.to_vec(),
+            allow_unknown: false,
+        }
+    }
+
+    pub fn with_max_required_age(mut self, age: u8) -> Self {
+        self.max_required_age = Some(age);
+        self
+    }
+
+    pub fn block(mut self, descriptor: ContentDescriptor) -> Self {
+        if !self.blocked.contains(&descriptor) {
+            self.blocked.push(descriptor);
+        }
+        self
+    }
+
+    pub fn with_allow_unknown(mut self, allow: bool) -> Self {
+        self.allow_unknown = allow;
+        self
+    }
+
+    /// Every reason the app is not allowed, empty if it is.
+    pub fn violations(&self, app: &AppDetails) -> Vec<PolicyViolation> {
+        let mut out = Vec::new();
+
+        if let (Some(max), Some(age)) = (self.max_required_age, app.required_age) {
+            if age > max {
+                out.push(PolicyViolation::RequiredAge(age));
+            }
+        }
+
+        let descriptors = app.content_descriptors.iter().flat_map(|c| &c.ids);
+        for descriptor in descriptors {
+            let blocked = match descriptor {
+                ContentDescriptor::Unknown(_) => !self.allow_unknown,
+                d => self.blocked.contains(d),
+            };
+            if blocked {
+                out.push(PolicyViolation::Descriptor(*descriptor));
+            }
+        }
+
+        out
+    }
+
+    pub fn allows(&self, app: &AppDetails) -> bool {
+        self.violations(app).is_empty()
+    }
+}
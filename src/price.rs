@@ -1,12 +1,18 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+use crate::lenient::Extra;
+use crate::money::{Currency, Money};
+use crate::types::ItemType;
+
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Featured {
     pub featured_win: Vec<FeaturedItem>,
     pub featured_mac: Vec<FeaturedItem>,
     pub featured_linux: Vec<FeaturedItem>,
     pub(crate) status: i32,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Deserialize)]
@@ -16,18 +22,29 @@ pub(crate) struct FeaturedCategories {
     pub featured_categories: HashMap<String, FeaturedCategorie>,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct FeaturedCategorie {
     pub id: String,
     pub name: String,
     pub items: Option<Vec<FeaturedItem>>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl FeaturedCategorie {
+    pub fn items_of_type(&self, item_type: ItemType) -> impl Iterator<Item = &FeaturedItem> {
+        self.items
+            .iter()
+            .flatten()
+            .filter(move |i| i.r#type == item_type)
+    }
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct FeaturedItem {
-    #[serde(rename(deserialize = "id"))]
+    #[serde(rename(deserialize = "id"), alias = "app_id")]
     pub app_id: usize,
-    pub r#type: u8,
+    pub r#type: ItemType,
     pub name: String,
     pub discounted: bool,
     pub discount_percent: u8,
@@ -36,7 +53,7 @@ pub struct FeaturedItem {
     /// Post-discount application price.
     pub final_price: u32,
     /// What currency prices are denoted in.
-    pub currency: String,
+    pub currency: Currency,
     pub large_capsule_image: String,
     pub small_capsule_image: String,
     pub windows_available: bool,
@@ -48,17 +65,54 @@ pub struct FeaturedItem {
     pub discount_expiration: Option<usize>,
     pub header_image: String,
     pub controller_support: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl FeaturedItem {
+    /// Pre-discount application price.
+    pub fn original_money(&self) -> Option<Money> {
+        self.original_price
+            .map(|p| Money::new(p.into(), self.currency))
+    }
+
+    /// Post-discount application price.
+    pub fn final_money(&self) -> Money {
+        Money::new(self.final_price.into(), self.currency)
+    }
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Price {
     /// What currency prices are denoted in.
-    pub currency: String,
+    pub currency: Currency,
     /// Pre-discount application price.
     pub initial: u32,
     /// Post-discount application price.
     pub r#final: u32,
     pub discount_percent: u8,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl Price {
+    /// Pre-discount application price.
+    pub fn initial_money(&self) -> Money {
+        Money::new(self.initial.into(), self.currency)
+    }
+
+    /// Post-discount application price.
+    pub fn final_money(&self) -> Money {
+        Money::new(self.r#final.into(), self.currency)
+    }
+
+    /// Amount saved by the discount.
+    pub fn savings(&self) -> Money {
+        Money::new(
+            i64::from(self.initial) - i64::from(self.r#final),
+            self.currency,
+        )
+    }
 }
 
 #[derive(Deserialize)]
@@ -73,10 +127,10 @@ pub(crate) struct PriceOverview {
 }
 
 /// Parsed application price.
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct AppPrice {
     /// Steam application ID
-    #[serde(skip_deserializing)]
+    #[serde(default)]
     pub app_id: u64,
     pub final_formatted: String,
     pub initial_formatted: String,
@@ -84,9 +138,16 @@ pub struct AppPrice {
     pub price: Price,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct PackagePrice {
     pub individual: u32,
     #[serde(flatten)]
     pub price: Price,
 }
+
+impl PackagePrice {
+    /// Price of the package apps when bought individually.
+    pub fn individual_money(&self) -> Money {
+        Money::new(self.individual.into(), self.price.currency)
+    }
+}
@@ -0,0 +1,919 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::Language;
+
+/// Calendar date without time zone.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i32,
+    /// 1 to 12
+    pub month: u8,
+    /// 1 to 31
+    pub day: u8,
+}
+
+impl Date {
+    /// Returns `None` if the day does not exist in that month.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+
+    /// UTC date of a Unix timestamp.
+    pub fn from_unix(secs: u64) -> Self {
+        Date::from_days((secs / 86_400) as i64)
+    }
+
+    /// Date `days` after 1970-01-01.
+    pub(crate) fn from_days(days: i64) -> Self {
+        // Howard Hinnant's civil_from_days
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+
+        Date { year, month, day }
+    }
+
+    /// `None` if the fields do not form a valid date.
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(&self) -> Option<chrono::NaiveDate> {
+        chrono::NaiveDate::from_ymd_opt(self.year, self.month.into(), self.day.into())
+    }
+
+    /// `None` if the fields do not form a valid date.
+    #[cfg(feature = "time")]
+    pub fn to_time(&self) -> Option<time::Date> {
+        let month = time::Month::try_from(self.month).ok()?;
+        time::Date::from_calendar_date(self.year, month, self.day).ok()
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Release date parsed from the localized string returned by the store.
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ReleasePeriod {
+    /// e.g. "14 Mar, 2023"
+    Exact(Date),
+    /// e.g. "March 2023"
+    Month { year: i32, month: u8 },
+    /// e.g. "Q3 2025"
+    Quarter { year: i32, quarter: u8 },
+    /// e.g. "2025"
+    Year(i32),
+    /// e.g. "Coming soon", "To be announced"
+    Unknown(String),
+}
+
+impl ReleasePeriod {
+    /// Parses a date as localized by the store for `language`.
+    /// Month names of `language` are tried first, then English,
+    /// then every other supported language.
+    pub fn parse(date: &str, language: &Language) -> Self {
+        parse(date, language).unwrap_or_else(|| ReleasePeriod::Unknown(date.trim().to_owned()))
+    }
+
+    /// First day the release may fall on.
+    pub fn earliest(&self) -> Option<Date> {
+        use ReleasePeriod::*;
+
+        match *self {
+            Exact(d) => Some(d),
+            Month { year, month } => Date::new(year, month, 1),
+            Quarter { year, quarter } => {
+                Date::new(year, quarter.checked_mul(3)?.checked_sub(2)?, 1)
+            }
+            Year(year) => Date::new(year, 1, 1),
+            Unknown(_) => None,
+        }
+    }
+
+    /// Last day the release may fall on.
+    pub fn latest(&self) -> Option<Date> {
+        use ReleasePeriod::*;
+
+        let (year, month) = match *self {
+            Exact(d) => return Some(d),
+            Month { year, month } => (year, month),
+            Quarter { year, quarter } => (year, quarter.checked_mul(3)?),
+            Year(year) => (year, 12),
+            Unknown(_) => return None,
+        };
+        Date::new(year, month, days_in_month(year, month))
+    }
+
+    pub fn is_known(&self) -> bool {
+        !matches!(self, ReleasePeriod::Unknown(_))
+    }
+
+    fn sort_key(&self) -> (Option<Date>, Option<Date>) {
+        (self.earliest(), self.latest())
+    }
+}
+
+/// Orders by earliest possible date, then by precision,
+/// so "March 2023" sorts before "2 Mar, 2023" and after "Q1 2023".
+/// Unknown dates come last, ordered by text.
+impl Ord for ReleasePeriod {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use ReleasePeriod::Unknown;
+
+        match (self, other) {
+            (Unknown(a), Unknown(b)) => a.cmp(b),
+            (Unknown(_), _) => Ordering::Greater,
+            (_, Unknown(_)) => Ordering::Less,
+            _ => {
+                let (a_start, a_end) = self.sort_key();
+                let (b_start, b_end) = other.sort_key();
+                a_start.cmp(&b_start).then(b_end.cmp(&a_end))
+            }
+        }
+    }
+}
+
+impl PartialOrd for ReleasePeriod {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug)]
+enum Token {
+    Num(u32, usize),
+    Word(String),
+}
+
+fn tokenize(s: &str) -> Vec<Token> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+
+    let flush = |current: &mut String, tokens: &mut Vec<Token>| {
+        if current.is_empty() {
+            return;
+        }
+        if current.starts_with(|c: char| c.is_ascii_digit()) {
+            if let Ok(n) = current.parse() {
+                tokens.push(Token::Num(n, current.len()));
+            }
+        } else {
+            tokens.push(Token::Word(current.to_lowercase()));
+        }
+        current.clear();
+    };
+
+    let is_thai = |c: Option<&char>| matches!(c, Some('\u{0E00}'..='\u{0E7F}'));
+
+    for (i, &c) in chars.iter().enumerate() {
+        // Thai month abbreviations are dotted, e.g. "มี.ค."
+        if c == '.' && is_thai(i.checked_sub(1).and_then(|p| chars.get(p))) {
+            continue;
+        }
+        if c.is_whitespace() || matches!(c, '.' | ',' | '/' | '-' | '(' | ')' | '،' | '，' | '\'')
+        {
+            flush(&mut current, &mut tokens);
+        } else if matches!(c, '年' | '月' | '日' | '년' | '월' | '일') {
+            flush(&mut current, &mut tokens);
+            tokens.push(Token::Word(c.to_string()));
+        } else {
+            let digit = current.starts_with(|c: char| c.is_ascii_digit());
+            if !current.is_empty() && digit != c.is_ascii_digit() {
+                flush(&mut current, &mut tokens);
+            }
+            current.push(c);
+        }
+    }
+    flush(&mut current, &mut tokens);
+
+    tokens
+}
+
+/// Month name stems per language, matched as prefixes of lowercased words.
+/// The longest matching stem wins.
+fn month_stems(language: &Language) -> [&'static [&'static str]; 12] {
+    use Language::*;
+
+    match language {
+        All | English => [
+            &["jan"],
+            &["feb"],
+            &["mar"],
+            &["apr"],
+            &["may"],
+            &["jun"],
+            &["jul"],
+            &["aug"],
+            &["sep"],
+            &["oct"],
+            &["nov"],
+            &["dec"],
+        ],
+        German => [
+            &["jan"],
+            &["feb"],
+            &["mär", "mar", "mrz"],
+            &["apr"],
+            &["mai"],
+            &["jun"],
+            &["jul"],
+            &["aug"],
+            &["sep"],
+            &["okt"],
+            &["nov"],
+            &["dez"],
+        ],
+        French => [
+            &["janv", "jan"],
+            &["fév", "fev"],
+            &["mars"],
+            &["avr"],
+            &["mai"],
+            &["juin"],
+            &["juil"],
+            &["aoû", "aou"],
+            &["sep"],
+            &["oct"],
+            &["nov"],
+            &["déc", "dec"],
+        ],
+        Spanish | Latam => [
+            &["ene"],
+            &["feb"],
+            &["mar"],
+            &["abr"],
+            &["may"],
+            &["jun"],
+            &["jul"],
+            &["ago"],
+            &["sep", "set"],
+            &["oct"],
+            &["nov"],
+            &["dic"],
+        ],
+        Italian => [
+            &["gen"],
+            &["feb"],
+            &["mar"],
+            &["apr"],
+            &["mag"],
+            &["giu"],
+            &["lug"],
+            &["ago"],
+            &["set"],
+            &["ott"],
+            &["nov"],
+            &["dic"],
+        ],
+        Portuguese | Brazilian => [
+            &["jan"],
+            &["fev"],
+            &["mar"],
+            &["abr"],
+            &["mai"],
+            &["jun"],
+            &["jul"],
+            &["ago"],
+            &["set"],
+            &["out"],
+            &["nov"],
+            &["dez"],
+        ],
+        Dutch => [
+            &["jan"],
+            &["feb"],
+            &["maa", "mrt"],
+            &["apr"],
+            &["mei"],
+            &["jun"],
+            &["jul"],
+            &["aug"],
+            &["sep"],
+            &["okt"],
+            &["nov"],
+            &["dec"],
+        ],
+        Danish | Norwegian | Swedish => [
+            &["jan"],
+            &["feb"],
+            &["mar"],
+            &["apr"],
+            &["maj", "mai"],
+            &["jun"],
+            &["jul"],
+            &["aug"],
+            &["sep"],
+            &["okt"],
+            &["nov"],
+            &["dec", "des"],
+        ],
+        Finnish => [
+            &["tammi"],
+            &["helmi"],
+            &["maalis"],
+            &["huhti"],
+            &["touko"],
+            &["kesä", "kesa"],
+            &["heinä", "heina"],
+            &["elo"],
+            &["syys"],
+            &["loka"],
+            &["marras"],
+            &["joulu"],
+        ],
+        Polish => [
+            &["sty"],
+            &["lut"],
+            &["mar"],
+            &["kwi"],
+            &["maj"],
+            &["cze"],
+            &["lip"],
+            &["sie"],
+            &["wrz"],
+            &["paź", "paz"],
+            &["lis"],
+            &["gru"],
+        ],
+        Czech => [
+            &["led"],
+            &["úno", "uno"],
+            &["bře", "bre"],
+            &["dub"],
+            &["kvě", "kve"],
+            &["červen", "červn", "cerven", "cervn", "čvn"],
+            &["červenc", "cervenc", "čvc"],
+            &["srp"],
+            &["zář", "zar"],
+            &["říj", "rij"],
+            &["lis"],
+            &["pro"],
+        ],
+        Hungarian => [
+            &["jan"],
+            &["feb"],
+            &["márc", "marc"],
+            &["ápr", "apr"],
+            &["máj", "maj"],
+            &["jún", "jun"],
+            &["júl", "jul"],
+            &["aug"],
+            &["szep"],
+            &["okt"],
+            &["nov"],
+            &["dec"],
+        ],
+        Greek => [
+            &["ιαν"],
+            &["φεβ"],
+            &["μαρ", "μάρ"],
+            &["απρ", "άπρ"],
+            &["μαΐ", "μαι", "μάι"],
+            &["ιουν", "ιούν"],
+            &["ιουλ", "ιούλ"],
+            &["αυγ", "αύγ"],
+            &["σεπ"],
+            &["οκτ"],
+            &["νοε", "νοέ"],
+            &["δεκ"],
+        ],
+        Romanian => [
+            &["ian"],
+            &["feb"],
+            &["mar"],
+            &["apr"],
+            &["mai"],
+            &["iun"],
+            &["iul"],
+            &["aug"],
+            &["sep"],
+            &["oct"],
+            &["noi"],
+            &["dec"],
+        ],
+        Russian => [
+            &["янв"],
+            &["фев"],
+            &["мар"],
+            &["апр"],
+            &["май", "мая"],
+            &["июн"],
+            &["июл"],
+            &["авг"],
+            &["сен"],
+            &["окт"],
+            &["ноя"],
+            &["дек"],
+        ],
+        Ukrainian => [
+            &["січ"],
+            &["лют"],
+            &["бер"],
+            &["кві"],
+            &["тра"],
+            &["чер"],
+            &["лип"],
+            &["сер"],
+            &["вер"],
+            &["жов"],
+            &["лис"],
+            &["гру"],
+        ],
+        Bulgarian => [
+            &["яну"],
+            &["фев"],
+            &["мар"],
+            &["апр"],
+            &["май"],
+            &["юни"],
+            &["юли"],
+            &["авг"],
+            &["сеп"],
+            &["окт"],
+            &["ное"],
+            &["дек"],
+        ],
+        Turkish => [
+            &["oca"],
+            &["şub", "sub"],
+            &["mar"],
+            &["nis"],
+            &["may"],
+            &["haz"],
+            &["tem"],
+            &["ağu", "agu"],
+            &["eyl"],
+            &["eki"],
+            &["kas"],
+            &["ara"],
+        ],
+        Indonesian => [
+            &["jan"],
+            &["feb"],
+            &["mar"],
+            &["apr"],
+            &["mei"],
+            &["jun"],
+            &["jul"],
+            &["agu", "ags"],
+            &["sep"],
+            &["okt"],
+            &["nov"],
+            &["des"],
+        ],
+        Thai => [
+            &["มค", "มกรา"],
+            &["กพ", "กุมภา"],
+            &["มีค", "มีนา"],
+            &["เมย", "เมษา"],
+            &["พค", "พฤษภา"],
+            &["มิย", "มิถุนา"],
+            &["กค", "กรกฎา"],
+            &["สค", "สิงหา"],
+            &["กย", "กันยา"],
+            &["ตค", "ตุลา"],
+            &["พย", "พฤศจิกา"],
+            &["ธค", "ธันวา"],
+        ],
+        Arabic => [
+            &["يناير"],
+            &["فبراير", "شباط"],
+            &["مارس", "آذار"],
+            &["أبريل", "ابريل", "نيسان"],
+            &["مايو", "أيار"],
+            &["يونيو", "حزيران"],
+            &["يوليو", "تموز"],
+            &["أغسطس", "اغسطس", "آب"],
+            &["سبتمبر", "أيلول"],
+            &["أكتوبر", "اكتوبر"],
+            &["نوفمبر"],
+            &["ديسمبر"],
+        ],
+        // Numeric dates with 年/月/日, 년/월/일 or "Thg" markers.
+        Schinese | Tchinese | Japanese | Koreana | Vietnamese => [&[]; 12],
+    }
+}
+
+fn month_from_word(word: &str, language: &Language) -> Option<u8> {
+    let find = |language: &Language, whole: bool| {
+        month_stems(language)
+            .iter()
+            .enumerate()
+            .flat_map(|(m, stems)| stems.iter().map(move |s| (m, s)))
+            .filter(|(_, s)| {
+                if whole {
+                    word == **s
+                } else {
+                    word.starts_with(*s)
+                }
+            })
+            .max_by_key(|(_, s)| s.len())
+            .map(|(m, _)| m as u8 + 1)
+    };
+
+    // Stores sometimes answer in another language than requested, but stems
+    // of other languages only count as whole words, or "prochainement"
+    // would read as Czech "prosinec".
+    match language {
+        Language::All => None,
+        l => find(l, false).or_else(|| find(&Language::English, false)),
+    }
+    .or_else(|| Language::ALL.iter().find_map(|l| find(l, true)))
+}
+
+fn quarter_from_word(word: &str) -> Option<u8> {
+    match word {
+        "i" | "一" => Some(1),
+        "ii" | "二" => Some(2),
+        "iii" | "三" => Some(3),
+        "iv" | "四" => Some(4),
+        _ => None,
+    }
+}
+
+fn is_quarter_word(word: &str) -> bool {
+    const WORDS: [&str; 15] = [
+        "q",
+        "t",
+        "kw",
+        "quarter",
+        "quartal",
+        "kwartaal",
+        "kvartal",
+        "kwartał",
+        "trimestre",
+        "čtvrtletí",
+        "negyedév",
+        "квартал",
+        "кв",
+        "çeyrek",
+        "quý",
+    ];
+    const CJK: [&str; 4] = ["季", "분기", "四半期", "半期"];
+
+    WORDS.contains(&word) || CJK.iter().any(|w| word.contains(w))
+}
+
+fn parse(date: &str, language: &Language) -> Option<ReleasePeriod> {
+    let tokens = tokenize(date);
+
+    let mut year: Option<i32> = None;
+    let mut month: Option<u8> = None;
+    let mut day: Option<u8> = None;
+    let mut quarter: Option<u8> = None;
+    let mut numbers: Vec<u32> = Vec::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let next = tokens.get(i + 1);
+        match &tokens[i] {
+            Token::Num(n, len) if *len >= 4 => year = Some(*n as i32),
+            Token::Num(n, _) => match next {
+                Some(Token::Word(w)) if ["年", "년"].contains(&w.as_str()) => {
+                    year = Some(*n as i32);
+                    i += 1;
+                }
+                Some(Token::Word(w)) if ["月", "월"].contains(&w.as_str()) => {
+                    month = Some(*n as u8);
+                    i += 1;
+                }
+                Some(Token::Word(w)) if ["日", "일"].contains(&w.as_str()) => {
+                    day = Some(*n as u8);
+                    i += 1;
+                }
+                Some(Token::Word(w)) if (1..=4).contains(n) && is_quarter_word(w) => {
+                    quarter = Some(*n as u8);
+                    i += 1;
+                }
+                _ => numbers.push(*n),
+            },
+            Token::Word(w) => match next {
+                Some(Token::Num(n, _)) if ["thg", "tháng"].contains(&w.as_str()) => {
+                    month = Some(*n as u8);
+                    i += 1;
+                }
+                Some(Token::Num(n, _)) if (1..=4).contains(n) && is_quarter_word(w) => {
+                    quarter = Some(*n as u8);
+                    i += 1;
+                }
+                Some(Token::Word(q)) if quarter_from_word(w).is_some() && is_quarter_word(q) => {
+                    quarter = quarter_from_word(w);
+                    i += 1;
+                }
+                _ => {
+                    if let Some(q) = w.strip_prefix('第').filter(|_| is_quarter_word(w)) {
+                        quarter = q
+                            .chars()
+                            .next()
+                            .and_then(|c| quarter_from_word(&c.to_string()));
+                    } else if month.is_none() {
+                        month = month_from_word(w, language);
+                    }
+                }
+            },
+        }
+        i += 1;
+    }
+
+    // Thai dates may use the Buddhist era.
+    if let (Language::Thai, Some(y)) = (language, year) {
+        if y > 2400 {
+            year = Some(y - 543);
+        }
+    }
+
+    // Purely numeric dates, e.g. "14.03.2023" or "2023-03-14".
+    if month.is_none() && quarter.is_none() {
+        match numbers.as_slice() {
+            [a, b] if year.is_some() => {
+                let year_first = matches!(tokens.first(), Some(Token::Num(_, len)) if *len >= 4);
+                // Day first unless the year leads or the second number can't be a month.
+                let (m, d) = if year_first || *b > 12 {
+                    (*a, *b)
+                } else {
+                    (*b, *a)
+                };
+                month = Some(m as u8);
+                day = Some(d as u8);
+            }
+            [m] if year.is_some() && (1..=12).contains(m) => month = Some(*m as u8),
+            _ => {}
+        }
+    } else if day.is_none() {
+        day = numbers
+            .iter()
+            .find(|n| (1..=31).contains(*n))
+            .map(|n| *n as u8);
+    }
+
+    let year = year?;
+
+    if let Some(quarter) = quarter {
+        return (1..=4)
+            .contains(&quarter)
+            .then_some(ReleasePeriod::Quarter { year, quarter });
+    }
+
+    match (month, day) {
+        (Some(month), Some(day)) => Date::new(year, month, day).map(ReleasePeriod::Exact),
+        (Some(month), None) if (1..=12).contains(&month) => {
+            Some(ReleasePeriod::Month { year, month })
+        }
+        (None, _) => Some(ReleasePeriod::Year(year)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Language::*;
+
+    fn exact(year: i32, month: u8, day: u8) -> ReleasePeriod {
+        ReleasePeriod::Exact(Date::new(year, month, day).unwrap())
+    }
+
+    fn month(year: i32, month: u8) -> ReleasePeriod {
+        ReleasePeriod::Month { year, month }
+    }
+
+    fn quarter(year: i32, quarter: u8) -> ReleasePeriod {
+        ReleasePeriod::Quarter { year, quarter }
+    }
+
+    fn check(language: Language, cases: &[(&str, ReleasePeriod)]) {
+        for (date, expected) in cases {
+            assert_eq!(
+                &ReleasePeriod::parse(date, &language),
+                expected,
+                "{:?}: {:?}",
+                language,
+                date
+            );
+        }
+    }
+
+    fn coming_soon(text: &str) -> (&str, ReleasePeriod) {
+        (text, ReleasePeriod::Unknown(text.to_owned()))
+    }
+
+    #[test]
+    fn english() {
+        check(
+            English,
+            &[
+                ("14 Mar, 2023", exact(2023, 3, 14)),
+                ("Mar 14, 2023", exact(2023, 3, 14)),
+                ("March 2023", month(2023, 3)),
+                ("Q3 2025", quarter(2025, 3)),
+                ("2025", ReleasePeriod::Year(2025)),
+                coming_soon("Coming soon"),
+                coming_soon("To be announced"),
+            ],
+        );
+    }
+
+    #[test]
+    fn german() {
+        check(
+            German,
+            &[
+                ("14. März 2023", exact(2023, 3, 14)),
+                ("14. Dez. 2023", exact(2023, 12, 14)),
+                ("März 2023", month(2023, 3)),
+                ("Q3 2025", quarter(2025, 3)),
+                ("2025", ReleasePeriod::Year(2025)),
+                coming_soon("Demnächst erhältlich"),
+            ],
+        );
+    }
+
+    #[test]
+    fn french() {
+        check(
+            French,
+            &[
+                ("14 déc. 2023", exact(2023, 12, 14)),
+                ("14 mars 2023", exact(2023, 3, 14)),
+                ("juillet 2023", month(2023, 7)),
+                ("T3 2025", quarter(2025, 3)),
+                ("2025", ReleasePeriod::Year(2025)),
+                coming_soon("Prochainement"),
+            ],
+        );
+    }
+
+    #[test]
+    fn spanish() {
+        check(
+            Spanish,
+            &[
+                ("14 ENE 2023", exact(2023, 1, 14)),
+                ("14 dic. 2023", exact(2023, 12, 14)),
+                ("agosto 2023", month(2023, 8)),
+                ("T3 2025", quarter(2025, 3)),
+                ("2025", ReleasePeriod::Year(2025)),
+                coming_soon("Próximamente"),
+            ],
+        );
+    }
+
+    #[test]
+    fn russian() {
+        check(
+            Russian,
+            &[
+                ("14 мар. 2023 г.", exact(2023, 3, 14)),
+                ("март 2023 г.", month(2023, 3)),
+                ("3 кв. 2025 г.", quarter(2025, 3)),
+                ("2025 г.", ReleasePeriod::Year(2025)),
+                coming_soon("Скоро"),
+            ],
+        );
+    }
+
+    #[test]
+    fn polish() {
+        check(
+            Polish,
+            &[
+                ("14 paź 2023", exact(2023, 10, 14)),
+                ("październik 2023", month(2023, 10)),
+                ("III kw. 2025", quarter(2025, 3)),
+                ("2025", ReleasePeriod::Year(2025)),
+                coming_soon("Wkrótce"),
+            ],
+        );
+    }
+
+    #[test]
+    fn brazilian() {
+        check(
+            Brazilian,
+            &[
+                ("14/mar./2023", exact(2023, 3, 14)),
+                ("14 de out. de 2023", exact(2023, 10, 14)),
+                ("outubro de 2023", month(2023, 10)),
+                ("T3 2025", quarter(2025, 3)),
+                ("2025", ReleasePeriod::Year(2025)),
+                coming_soon("Em breve"),
+            ],
+        );
+    }
+
+    #[test]
+    fn numeric() {
+        check(
+            Dutch,
+            &[
+                ("14-03-2023", exact(2023, 3, 14)),
+                ("2023-03-14", exact(2023, 3, 14)),
+            ],
+        );
+    }
+
+    #[test]
+    fn thai() {
+        check(
+            Thai,
+            &[
+                ("14 มี.ค. 2566", exact(2023, 3, 14)),
+                ("มีนาคม 2023", month(2023, 3)),
+                ("2025", ReleasePeriod::Year(2025)),
+                coming_soon("เร็วๆ นี้"),
+            ],
+        );
+    }
+
+    #[test]
+    fn japanese() {
+        check(
+            Japanese,
+            &[
+                ("2023年3月14日", exact(2023, 3, 14)),
+                ("2023年3月", month(2023, 3)),
+                ("2025年第3四半期", quarter(2025, 3)),
+                ("2025年", ReleasePeriod::Year(2025)),
+                coming_soon("近日登場"),
+            ],
+        );
+    }
+
+    #[test]
+    fn simplified_chinese() {
+        check(
+            Schinese,
+            &[
+                ("2023 年 3 月 14 日", exact(2023, 3, 14)),
+                ("2023 年 3 月", month(2023, 3)),
+                ("2025 年第三季度", quarter(2025, 3)),
+                ("2025 年", ReleasePeriod::Year(2025)),
+                coming_soon("即将推出"),
+            ],
+        );
+    }
+
+    #[test]
+    fn korean() {
+        check(
+            Koreana,
+            &[
+                ("2023년 3월 14일", exact(2023, 3, 14)),
+                ("2023년 3월", month(2023, 3)),
+                ("2025년 3분기", quarter(2025, 3)),
+                ("2025년", ReleasePeriod::Year(2025)),
+                coming_soon("출시 예정"),
+            ],
+        );
+    }
+
+    #[test]
+    fn vietnamese() {
+        check(
+            Vietnamese,
+            &[
+                ("14 Thg 03, 2023", exact(2023, 3, 14)),
+                ("Tháng 3 2023", month(2023, 3)),
+                ("Quý 3 2025", quarter(2025, 3)),
+                ("2025", ReleasePeriod::Year(2025)),
+                coming_soon("Sắp ra mắt"),
+            ],
+        );
+    }
+
+    #[test]
+    fn month_of_other_language() {
+        check(
+            English,
+            &[
+                ("14 déc. 2023", exact(2023, 12, 14)),
+                ("14. Okt. 2023", exact(2023, 10, 14)),
+                ("Prochainement 2025", ReleasePeriod::Year(2025)),
+                ("Projected 2025", ReleasePeriod::Year(2025)),
+            ],
+        );
+    }
+}
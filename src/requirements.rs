@@ -0,0 +1,363 @@
+use serde::{Deserialize, Serialize};
+
+use crate::app::{AppDetails, Requirements};
+use crate::html::decode_entities;
+
+/// System requirements of one tier (minimum or recommended) parsed from store HTML.
+///
+/// Labels are matched in English, so the request language should be English.
+/// Sizes are read with binary units, "8 GB" being 8 * 1024^3 bytes.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct SystemRequirements {
+    pub os: Option<String>,
+    pub processor: Option<String>,
+    /// RAM in bytes
+    pub memory: Option<u64>,
+    pub graphics: Option<String>,
+    /// e.g. "Version 11"
+    pub directx: Option<String>,
+    /// Video memory in bytes, from a dedicated line or from `graphics`
+    pub vram: Option<u64>,
+    /// Disk space in bytes
+    pub storage: Option<u64>,
+    pub network: Option<String>,
+    pub sound_card: Option<String>,
+    pub notes: Option<String>,
+    /// "Requires a 64-bit processor and operating system"
+    pub requires_64_bit: bool,
+    /// Lines that did not match any known label.
+    pub other: Vec<String>,
+    /// Original HTML, for fallback rendering.
+    pub raw: String,
+}
+
+/// Parsed [`Requirements`] of one platform.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct RequirementTiers {
+    pub minimum: Option<SystemRequirements>,
+    pub recommended: Option<SystemRequirements>,
+}
+
+/// Parsed requirements of every platform.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct PlatformRequirements {
+    pub pc: Option<RequirementTiers>,
+    pub mac: Option<RequirementTiers>,
+    pub linux: Option<RequirementTiers>,
+}
+
+impl Requirements {
+    pub fn parse(&self) -> RequirementTiers {
+        RequirementTiers {
+            minimum: self.minimum.as_deref().map(SystemRequirements::parse),
+            recommended: self.recommended.as_deref().map(SystemRequirements::parse),
+        }
+    }
+}
+
+impl AppDetails {
+    /// Parsed `pc_requirements`, `mac_requirements` and `linux_requirements`.
+    pub fn system_requirements(&self) -> PlatformRequirements {
+        PlatformRequirements {
+            pc: self.pc_requirements.as_ref().map(Requirements::parse),
+            mac: self.mac_requirements.as_ref().map(Requirements::parse),
+            linux: self.linux_requirements.as_ref().map(Requirements::parse),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Field {
+    Heading,
+    Os,
+    Processor,
+    Memory,
+    Graphics,
+    DirectX,
+    Vram,
+    Storage,
+    Network,
+    SoundCard,
+    Notes,
+}
+
+/// Known labels, longest first so "hard disk space" wins over "hard disk".
+const LABELS: [(&str, Field); 37] = [
+    ("additional requirements", Field::Notes),
+    ("other requirements", Field::Notes),
+    ("additional notes", Field::Notes),
+    ("hard disk space", Field::Storage),
+    ("free disk space", Field::Storage),
+    ("operating system", Field::Os),
+    ("graphics memory", Field::Vram),
+    ("system memory", Field::Memory),
+    ("graphics card", Field::Graphics),
+    ("video memory", Field::Vram),
+    ("recommended", Field::Heading),
+    ("hard drive", Field::Storage),
+    ("disk space", Field::Storage),
+    ("video card", Field::Graphics),
+    ("sound card", Field::SoundCard),
+    ("processor", Field::Processor),
+    ("hard disk", Field::Storage),
+    ("video ram", Field::Vram),
+    ("direct x", Field::DirectX),
+    ("graphics", Field::Graphics),
+    ("internet", Field::Network),
+    ("minimum", Field::Heading),
+    ("directx", Field::DirectX),
+    ("storage", Field::Storage),
+    ("network", Field::Network),
+    ("memory", Field::Memory),
+    ("notes", Field::Notes),
+    ("video", Field::Graphics),
+    ("sound", Field::SoundCard),
+    ("audio", Field::SoundCard),
+    ("other", Field::Notes),
+    ("vram", Field::Vram),
+    ("cpu", Field::Processor),
+    ("gpu", Field::Graphics),
+    ("ram", Field::Memory),
+    ("hdd", Field::Storage),
+    ("os", Field::Os),
+];
+
+impl SystemRequirements {
+    /// Parses one tier of requirements, e.g. `Requirements.minimum`.
+    pub fn parse(html: &str) -> Self {
+        let mut out = SystemRequirements {
+            raw: html.to_owned(),
+            ..Default::default()
+        };
+
+        for line in html_lines(html) {
+            for (field, value) in split_labels(&line) {
+                out.set(field, value);
+            }
+        }
+
+        if out.vram.is_none() {
+            out.vram = out.graphics.as_deref().and_then(parse_size);
+        }
+
+        out
+    }
+
+    /// Major DirectX version, e.g. 11 for "Version 11".
+    pub fn directx_version(&self) -> Option<u32> {
+        let directx = self.directx.as_deref()?;
+        let digits: String = directx
+            .chars()
+            .skip_while(|c| !c.is_ascii_digit())
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        digits.parse().ok()
+    }
+
+    fn set(&mut self, field: Option<Field>, value: String) {
+        let value = value.trim().to_owned();
+        let Some(field) = field else {
+            if value.to_ascii_lowercase().contains("64-bit") {
+                self.requires_64_bit = true;
+            } else if !value.is_empty() {
+                self.other.push(value);
+            }
+            return;
+        };
+        if value.is_empty() {
+            return;
+        }
+
+        let text = |slot: &mut Option<String>| match slot {
+            Some(s) => {
+                s.push_str("; ");
+                s.push_str(&value);
+            }
+            None => *slot = Some(value.clone()),
+        };
+
+        match field {
+            Field::Heading => self.set(None, value),
+            Field::Os => text(&mut self.os),
+            Field::Processor => text(&mut self.processor),
+            Field::Memory => self.memory = self.memory.or_else(|| parse_size(&value)),
+            Field::Graphics => text(&mut self.graphics),
+            Field::DirectX => text(&mut self.directx),
+            Field::Vram => self.vram = self.vram.or_else(|| parse_size(&value)),
+            Field::Storage => self.storage = self.storage.or_else(|| parse_size(&value)),
+            Field::Network => text(&mut self.network),
+            Field::SoundCard => text(&mut self.sound_card),
+            Field::Notes => text(&mut self.notes),
+        }
+    }
+}
+
+/// Converts list items and line breaks to lines and drops the remaining tags.
+fn html_lines(html: &str) -> Vec<String> {
+    let mut text = String::with_capacity(html.len());
+    let mut tag = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => {
+                in_tag = true;
+                tag.clear();
+            }
+            '>' if in_tag => {
+                in_tag = false;
+                let name: String = tag
+                    .trim_start_matches('/')
+                    .chars()
+                    .take_while(|c| c.is_ascii_alphanumeric())
+                    .collect::<String>()
+                    .to_ascii_lowercase();
+                if ["br", "li", "p", "ul", "ol", "div"].contains(&name.as_str()) {
+                    text.push('\n');
+                }
+            }
+            c if in_tag => tag.push(c),
+            c => text.push(c),
+        }
+    }
+
+    decode_entities(&text)
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Splits a line on every known "Label:" it contains.
+/// Text before the first label is returned with no field.
+fn split_labels(line: &str) -> Vec<(Option<Field>, String)> {
+    // ASCII lowercasing keeps byte offsets, so they are valid in `line` too.
+    let lower = line.to_ascii_lowercase();
+    let mut marks: Vec<(usize, usize, Field)> = Vec::new();
+
+    let mut i = 0;
+    while i < lower.len() {
+        // Labels only start on a char boundary after a non-alphanumeric char,
+        // e.g. not inside "Intel® Core™ i5".
+        let boundary = lower.is_char_boundary(i)
+            && lower[..i]
+                .chars()
+                .next_back()
+                .is_none_or(|c| !c.is_alphanumeric());
+        let found = boundary
+            .then(|| {
+                LABELS.iter().find_map(|(label, field)| {
+                    let rest = lower[i..].strip_prefix(label)?;
+                    let trimmed = rest.trim_start_matches([' ', '*']);
+                    let after = trimmed.strip_prefix(':')?;
+                    Some((lower.len() - after.len(), *field))
+                })
+            })
+            .flatten();
+
+        match found {
+            Some((end, field)) => {
+                marks.push((i, end, field));
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+
+    let mut out = Vec::with_capacity(marks.len() + 1);
+    let head = &line[..marks.first().map_or(line.len(), |m| m.0)];
+    if !head.trim().is_empty() {
+        out.push((None, head.to_owned()));
+    }
+    for (n, (_, end, field)) in marks.iter().enumerate() {
+        let stop = marks.get(n + 1).map_or(line.len(), |m| m.0);
+        out.push((Some(*field), line[*end..stop].to_owned()));
+    }
+
+    out
+}
+
+/// Reads the first size in text like "8 GB RAM", "1.5GB", "512 MB" or "50 Go".
+fn parse_size(text: &str) -> Option<u64> {
+    let lower = text.to_lowercase();
+    let chars: Vec<char> = lower.chars().collect();
+
+    let mut i = 0;
+    while i < chars.len() {
+        if !chars[i].is_ascii_digit() || (i > 0 && chars[i - 1].is_alphanumeric()) {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len() && (chars[i].is_ascii_digit() || matches!(chars[i], '.' | ',')) {
+            i += 1;
+        }
+        let number: String = chars[start..i]
+            .iter()
+            .map(|c| if *c == ',' { '.' } else { *c })
+            .collect();
+        let number = number.trim_end_matches('.');
+
+        let unit: String = chars[i..]
+            .iter()
+            .skip_while(|c| c.is_whitespace())
+            .take_while(|c| c.is_alphabetic())
+            .collect();
+
+        let power = match unit.as_str() {
+            "kb" | "kib" | "ko" | "кб" => 1,
+            "mb" | "mib" | "mo" | "мб" => 2,
+            "gb" | "gib" | "go" | "гб" | "g" => 3,
+            "tb" | "tib" | "to" | "тб" => 4,
+            _ => continue,
+        };
+
+        if let Ok(value) = number.parse::<f64>() {
+            return Some((value * 1024_f64.powi(power)).round() as u64);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GB: u64 = 1024 * 1024 * 1024;
+
+    #[test]
+    fn parses_labels() {
+        let req = SystemRequirements::parse(
+            "<strong>Minimum:</strong><br><ul class=\"bb_ul\"><li>Requires a 64-bit processor and operating system<br></li>\
+             <li><strong>OS:</strong> Windows 10<br></li><li><strong>Memory:</strong> 8 GB RAM<br></li>\
+             <li><strong>Graphics:</strong> GTX 1060 6GB<br></li><li><strong>DirectX:</strong> Version 12<br></li>\
+             <li><strong>Storage:</strong> 50 GB available space</li></ul>",
+        );
+
+        assert!(req.requires_64_bit);
+        assert_eq!(req.os.as_deref(), Some("Windows 10"));
+        assert_eq!(req.memory, Some(8 * GB));
+        assert_eq!(req.vram, Some(6 * GB));
+        assert_eq!(req.directx_version(), Some(12));
+        assert_eq!(req.storage, Some(50 * GB));
+    }
+
+    #[test]
+    fn non_ascii_text() {
+        let req = SystemRequirements::parse(
+            "<li><strong>Processor:</strong> Intel® Core™ i5-4460 / AMD Ryzen™ 3 1200<br></li>\
+             <li><strong>Graphics:</strong> NVIDIA® GeForce® GTX 960 (2 Go)<br></li>\
+             <li>Mémoire vive™ requise</li>",
+        );
+
+        assert_eq!(
+            req.processor.as_deref(),
+            Some("Intel® Core™ i5-4460 / AMD Ryzen™ 3 1200")
+        );
+        assert_eq!(req.vram, Some(2 * GB));
+        assert_eq!(req.other, vec!["Mémoire vive™ requise".to_owned()]);
+    }
+}
@@ -0,0 +1,58 @@
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde_json::Value;
+use url::Url;
+
+use crate::lenient::ParseWarning;
+use crate::steam::SteamErr;
+
+/// Typed response together with the payload it was parsed from.
+#[derive(Clone, Debug)]
+pub struct Response<T> {
+    pub data: T,
+    /// Requested URL, including query parameters
+    pub url: Url,
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    /// Body exactly as received
+    pub raw: String,
+    /// Values dropped by lenient parsing, empty otherwise
+    pub warnings: Vec<ParseWarning>,
+}
+
+impl<T> Response<T> {
+    /// Body parsed as untyped JSON.
+    pub fn raw_json(&self) -> Result<Value, SteamErr> {
+        serde_json::from_str(&self.raw).map_err(SteamErr::JsonError)
+    }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    /// Transforms the data, keeping the raw response.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Response<U> {
+        Response {
+            data: f(self.data),
+            url: self.url,
+            status: self.status,
+            headers: self.headers,
+            raw: self.raw,
+            warnings: self.warnings,
+        }
+    }
+
+    pub(crate) fn try_map<U, F>(self, f: F) -> Result<Response<U>, SteamErr>
+    where
+        F: FnOnce(T) -> Result<U, SteamErr>,
+    {
+        Ok(Response {
+            data: f(self.data)?,
+            url: self.url,
+            status: self.status,
+            headers: self.headers,
+            raw: self.raw,
+            warnings: self.warnings,
+        })
+    }
+}
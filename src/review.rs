@@ -1,11 +1,23 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::future::BoxFuture;
+use futures::Stream;
 use serde::{Deserialize, Serialize};
+use serde_aux::prelude::*;
 
+use crate::lenient::{Extra, ParseWarning};
+use crate::response::Response;
+use crate::steam::{Steam, SteamErr};
 use crate::types::Language;
 
 const MAX_REVIEW_NUM_PER_PAGE: u8 = 100;
 const MAX_DAY_RANGE: u16 = 365;
 
-#[derive(Default, Clone, Debug, Hash)]
+#[derive(Deserialize, Serialize, Default, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ReviewsFilter {
     /// helpfulness, creation time or last updated time
     pub filter: Option<Filter>,
@@ -29,9 +41,69 @@ pub struct ReviewsFilter {
     /// by default, off-topic reviews (aka "Review Bombs") are filtered out
     /// and are not returned in this API.
     pub offtopic_activity: OfftopicActivity,
+    /// Unix timestamp, start of the date range
+    pub start_date: Option<u64>,
+    /// Unix timestamp, end of the date range
+    pub end_date: Option<u64>,
+    /// whether to keep or drop reviews between `start_date` and `end_date`
+    pub date_range_type: Option<DateRangeType>,
+    /// minimum playtime of the author in hours
+    pub playtime_min: Option<u32>,
+    /// maximum playtime of the author in hours
+    pub playtime_max: Option<u32>,
+    /// device the playtime filter counts
+    pub playtime_device: Option<PlaytimeDevice>,
 }
 
 impl ReviewsFilter {
+    pub fn builder() -> ReviewsFilterBuilder {
+        ReviewsFilterBuilder::new()
+    }
+
+    /// Checks for values and combinations Steam ignores or rejects.
+    /// Called by [`Steam::reviews`] and [`Steam::reviews_stream`] before any request.
+    pub fn validate(&self) -> Result<(), SteamErr> {
+        let invalid = |reason: &str| Err(SteamErr::InvalidReviewsFilter(reason.to_owned()));
+
+        if self.day_range.is_some_and(|d| d == 0 || d > MAX_DAY_RANGE) {
+            return invalid("day_range must be from 1 to 365");
+        }
+        if self
+            .num_per_page
+            .is_some_and(|n| n == 0 || n > MAX_REVIEW_NUM_PER_PAGE)
+        {
+            return invalid("num_per_page must be from 1 to 100");
+        }
+        if self.day_range.is_some() && !matches!(self.filter, None | Some(Filter::All)) {
+            return invalid("day_range is only applicable for the all filter");
+        }
+        if self.day_range.is_some() && (self.start_date.is_some() || self.end_date.is_some()) {
+            return invalid("day_range can't be combined with start_date or end_date");
+        }
+        if self.start_date.is_some() != self.end_date.is_some() {
+            return invalid("start_date and end_date must be set together");
+        }
+        if let (Some(start), Some(end)) = (self.start_date, self.end_date) {
+            if start > end {
+                return invalid("start_date is after end_date");
+            }
+        }
+        if matches!(
+            self.date_range_type,
+            Some(DateRangeType::Include | DateRangeType::Exclude)
+        ) && (self.start_date.is_none() || self.end_date.is_none())
+        {
+            return invalid("date_range_type needs both start_date and end_date");
+        }
+        if let (Some(min), Some(max)) = (self.playtime_min, self.playtime_max) {
+            if min > max {
+                return invalid("playtime_min is greater than playtime_max");
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn to_url_params(&self) -> Vec<(&str, String)> {
         let mut params: Vec<(&str, String)> = vec![("json", "1".to_owned())];
 
@@ -51,24 +123,131 @@ impl ReviewsFilter {
             params.push(("purchase_type", purchase_type.as_ref().to_owned()));
         };
         if let Some(day_range) = self.day_range {
-            params.push(("day_range", day_range.max(MAX_DAY_RANGE).to_string()));
+            params.push(("day_range", day_range.to_string()));
         };
         if let Some(num_per_page) = self.num_per_page {
-            params.push((
-                "num_per_page",
-                num_per_page.max(MAX_REVIEW_NUM_PER_PAGE).to_string(),
-            ));
+            params.push(("num_per_page", num_per_page.to_string()));
         }
 
         if self.offtopic_activity == OfftopicActivity::Include {
             params.push(("filter_offtopic_activity", 0.to_string()));
         }
 
+        if let Some(start_date) = self.start_date {
+            params.push(("start_date", start_date.to_string()));
+        }
+        if let Some(end_date) = self.end_date {
+            params.push(("end_date", end_date.to_string()));
+        }
+        match &self.date_range_type {
+            Some(date_range_type) => {
+                params.push(("date_range_type", date_range_type.as_ref().to_owned()));
+            }
+            // dates alone are ignored by Steam
+            None if self.start_date.is_some() || self.end_date.is_some() => {
+                params.push((
+                    "date_range_type",
+                    DateRangeType::Include.as_ref().to_owned(),
+                ));
+            }
+            None => {}
+        }
+        if let Some(playtime_min) = self.playtime_min {
+            params.push(("playtime_filter_min", playtime_min.to_string()));
+        }
+        if let Some(playtime_max) = self.playtime_max {
+            params.push(("playtime_filter_max", playtime_max.to_string()));
+        }
+        if let Some(playtime_device) = &self.playtime_device {
+            params.push(("playtime_device", playtime_device.as_ref().to_owned()));
+        }
+
         params
     }
 }
 
-#[derive(Default, Clone, Debug, Hash)]
+/// Builder for [`ReviewsFilter`], clamping values to what Steam accepts
+/// and rejecting invalid combinations on [`ReviewsFilterBuilder::build`].
+#[derive(Default, Clone, Debug)]
+pub struct ReviewsFilterBuilder {
+    filter: ReviewsFilter,
+}
+
+impl ReviewsFilterBuilder {
+    pub fn new() -> Self {
+        ReviewsFilterBuilder::default()
+    }
+
+    pub fn build(self) -> Result<ReviewsFilter, SteamErr> {
+        self.filter.validate()?;
+        Ok(self.filter)
+    }
+
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        self.filter.filter = Some(filter);
+        self
+    }
+
+    pub fn with_review_type(mut self, review_type: ReviewType) -> Self {
+        self.filter.review_type = Some(review_type);
+        self
+    }
+
+    pub fn with_purchase_type(mut self, purchase_type: PurchaseType) -> Self {
+        self.filter.purchase_type = Some(purchase_type);
+        self
+    }
+
+    pub fn with_language(mut self, language: &Language) -> Self {
+        self.filter.language = Some(language.clone());
+        self
+    }
+
+    /// Clamped to 1..=365.
+    pub fn with_day_range(mut self, days: u16) -> Self {
+        self.filter.day_range = Some(days.clamp(1, MAX_DAY_RANGE));
+        self
+    }
+
+    pub fn with_cursor(mut self, cursor: &str) -> Self {
+        self.filter.cursor = Some(cursor.to_owned());
+        self
+    }
+
+    /// Clamped to 1..=100.
+    pub fn with_num_per_page(mut self, num: u8) -> Self {
+        self.filter.num_per_page = Some(num.clamp(1, MAX_REVIEW_NUM_PER_PAGE));
+        self
+    }
+
+    pub fn with_offtopic_activity(mut self, offtopic_activity: OfftopicActivity) -> Self {
+        self.filter.offtopic_activity = offtopic_activity;
+        self
+    }
+
+    /// Unix timestamps bounding the reviews to keep or drop.
+    pub fn with_date_range(mut self, start: u64, end: u64, range_type: DateRangeType) -> Self {
+        self.filter.start_date = Some(start);
+        self.filter.end_date = Some(end);
+        self.filter.date_range_type = Some(range_type);
+        self
+    }
+
+    /// Playtime bounds in hours.
+    pub fn with_playtime(mut self, min: Option<u32>, max: Option<u32>) -> Self {
+        self.filter.playtime_min = min;
+        self.filter.playtime_max = max;
+        self
+    }
+
+    pub fn with_playtime_device(mut self, device: PlaytimeDevice) -> Self {
+        self.filter.playtime_device = Some(device);
+        self
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Clone, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
 pub enum Filter {
     /// (default) sorted by helpfulness, with sliding windows based on day_range parameter,
     /// will always find results to return.
@@ -92,7 +271,8 @@ impl AsRef<str> for Filter {
     }
 }
 
-#[derive(Default, Clone, Debug, Hash)]
+#[derive(Deserialize, Serialize, Default, Clone, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
 pub enum ReviewType {
     /// all reviews (default)
     #[default]
@@ -115,7 +295,8 @@ impl AsRef<str> for ReviewType {
     }
 }
 
-#[derive(Default, Clone, Debug, Hash)]
+#[derive(Deserialize, Serialize, Default, Clone, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
 pub enum PurchaseType {
     /// all reviews
     #[default]
@@ -138,7 +319,8 @@ impl AsRef<str> for PurchaseType {
     }
 }
 
-#[derive(Default, Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Default, Clone, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
 pub enum OfftopicActivity {
     /// exclude off-topic reviews (aka "Review Bombs")
     #[default]
@@ -147,18 +329,65 @@ pub enum OfftopicActivity {
     Include,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Default, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DateRangeType {
+    /// ignore `start_date` and `end_date`
+    #[default]
+    All,
+    /// only reviews between `start_date` and `end_date`
+    Include,
+    /// only reviews outside of `start_date` and `end_date`
+    Exclude,
+}
+
+impl AsRef<str> for DateRangeType {
+    fn as_ref(&self) -> &str {
+        use DateRangeType::*;
+
+        match &self {
+            All => "all",
+            Include => "include",
+            Exclude => "exclude",
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PlaytimeDevice {
+    /// playtime on any device
+    #[default]
+    All,
+    /// only playtime on Steam Deck
+    Deck,
+}
+
+impl AsRef<str> for PlaytimeDevice {
+    fn as_ref(&self) -> &str {
+        use PlaytimeDevice::*;
+
+        match &self {
+            All => "all",
+            Deck => "deck",
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Reviews {
     pub(crate) success: u8,
-    #[serde(skip_deserializing)]
+    #[serde(default)]
     pub app_id: u64,
     pub reviews: Vec<Review>,
     pub query_summary: QuerySummary,
     /// The value to pass into the next request as the cursor to retrieve the next batch of reviews
     pub cursor: String,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct QuerySummary {
     /// The number of reviews returned in this response
     pub num_reviews: u32,
@@ -172,35 +401,446 @@ pub struct QuerySummary {
     pub total_negative: Option<u32>,
     /// Total number of reviews matching the query parameters
     pub total_reviews: Option<u32>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Result of [`Steam::review_summaries`].
+#[derive(Debug, Default)]
+pub struct ReviewSummaries {
+    /// Summary of every app fetched or cached
+    pub summaries: HashMap<u64, QuerySummary>,
+    /// Apps whose summary couldn't be fetched
+    pub failed: HashMap<u64, SteamErr>,
+    /// Values dropped by lenient parsing, empty otherwise
+    pub warnings: Vec<ParseWarning>,
+}
+
+impl ReviewSummaries {
+    /// No app failed.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Typed view of the playtime fields of [`Author`].
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Playtime {
+    pub forever: Duration,
+    pub last_two_weeks: Duration,
+    pub at_review: Duration,
+    /// Part of `at_review` played on Steam Deck, if reported
+    pub deck_at_review: Option<Duration>,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Author {
-    #[serde(rename(deserialize = "steamid"))]
+    #[serde(rename(deserialize = "steamid"), alias = "user_id")]
     pub user_id: String,
     pub num_games_owned: u64,
     pub num_reviews: u64,
+    /// Minutes
     pub playtime_forever: u64,
+    /// Minutes
     pub playtime_last_two_weeks: u64,
+    /// Minutes
     pub playtime_at_review: u64,
+    /// Minutes played on Steam Deck at review time,
+    /// the only per-device playtime Steam reports
+    pub deck_playtime_at_review: Option<u64>,
+    /// Unix timestamp, 0 if never played
     pub last_played: u64,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl Author {
+    pub fn playtime(&self) -> Playtime {
+        Playtime {
+            forever: minutes(self.playtime_forever),
+            last_two_weeks: minutes(self.playtime_last_two_weeks),
+            at_review: minutes(self.playtime_at_review),
+            deck_at_review: self.deck_playtime_at_review.map(minutes),
+        }
+    }
+
+    /// `None` if the author never played the app.
+    pub fn last_played_at(&self) -> Option<SystemTime> {
+        (self.last_played != 0).then(|| timestamp(self.last_played))
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn last_played_chrono(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.last_played_at().map(Into::into)
+    }
+
+    #[cfg(feature = "time")]
+    pub fn last_played_time(&self) -> Option<time::OffsetDateTime> {
+        self.last_played_at().map(Into::into)
+    }
+}
+
+/// Helpfulness score of a [`Review`] from 0 to 1.
+///
+/// Compared and hashed by its bits, so it can be used in sets and maps.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default)]
+#[serde(transparent)]
+pub struct VoteScore(#[serde(deserialize_with = "deserialize_number_from_string")] pub f64);
+
+impl PartialEq for VoteScore {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for VoteScore {}
+
+impl Hash for VoteScore {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+impl From<VoteScore> for f64 {
+    fn from(score: VoteScore) -> Self {
+        score.0
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Review {
-    #[serde(rename(deserialize = "recommendationid"))]
+    #[serde(rename(deserialize = "recommendationid"), alias = "review_id")]
     pub review_id: String,
     pub author: Author,
     pub language: String,
     pub review: String,
+    /// Unix timestamp
     pub timestamp_created: u64,
+    /// Unix timestamp
     pub timestamp_updated: u64,
     pub received_for_free: bool,
     pub steam_purchase: bool,
     pub voted_up: bool,
     pub votes_up: u64,
     pub votes_funny: u64,
-    pub weighted_vote_score: String,
+    pub weighted_vote_score: VoteScore,
     pub written_during_early_access: bool,
     pub comment_count: Option<usize>,
+    /// Author played mostly on Steam Deck
+    pub primarily_steam_deck: Option<bool>,
+    pub hidden_in_steam_china: Option<bool>,
+    pub steam_china_location: Option<String>,
+    /// Developer's reply, HTML
+    pub developer_response: Option<String>,
+    /// Unix timestamp of the developer's reply
+    pub timestamp_dev_responded: Option<u64>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl Review {
+    pub fn created_at(&self) -> SystemTime {
+        timestamp(self.timestamp_created)
+    }
+
+    pub fn updated_at(&self) -> SystemTime {
+        timestamp(self.timestamp_updated)
+    }
+
+    pub fn is_edited(&self) -> bool {
+        self.timestamp_updated > self.timestamp_created
+    }
+
+    /// `None` if the developer has not replied.
+    pub fn developer_responded_at(&self) -> Option<SystemTime> {
+        self.timestamp_dev_responded.map(timestamp)
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn created_chrono(&self) -> chrono::DateTime<chrono::Utc> {
+        self.created_at().into()
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn updated_chrono(&self) -> chrono::DateTime<chrono::Utc> {
+        self.updated_at().into()
+    }
+
+    #[cfg(feature = "time")]
+    pub fn created_time(&self) -> time::OffsetDateTime {
+        self.created_at().into()
+    }
+
+    #[cfg(feature = "time")]
+    pub fn updated_time(&self) -> time::OffsetDateTime {
+        self.updated_at().into()
+    }
+}
+
+/// Reviews of an application across pages, see [`Steam::reviews_stream`].
+///
+/// Stops when Steam returns an empty page or repeats a cursor,
+/// and skips reviews already seen on earlier pages.
+pub struct ReviewStream {
+    steam: Steam,
+    app_id: u64,
+    filter: ReviewsFilter,
+    /// Cursor of the next page, `None` once the last page was fetched.
+    cursor: Option<String>,
+    seen_cursors: HashSet<String>,
+    seen_reviews: HashSet<String>,
+    buffer: VecDeque<Review>,
+    summary: Option<QuerySummary>,
+    max_reviews: Option<usize>,
+    stop_before: Option<SystemTime>,
+    yielded: usize,
+    pending: Option<BoxFuture<'static, Result<Response<Reviews>, SteamErr>>>,
+    warnings: Vec<ParseWarning>,
+    /// Why the filter was rejected, yielded once before the stream ends.
+    invalid: Option<SteamErr>,
+}
+
+impl ReviewStream {
+    pub(crate) fn new(steam: Steam, app_id: u64, mut filter: ReviewsFilter) -> Self {
+        let cursor = filter.cursor.take().unwrap_or_else(|| "*".to_owned());
+        filter.num_per_page = filter.num_per_page.or(Some(MAX_REVIEW_NUM_PER_PAGE));
+        let invalid = filter.validate().err();
+
+        ReviewStream {
+            steam,
+            app_id,
+            filter,
+            cursor: invalid.is_none().then_some(cursor),
+            seen_cursors: HashSet::new(),
+            seen_reviews: HashSet::new(),
+            buffer: VecDeque::new(),
+            summary: None,
+            max_reviews: None,
+            stop_before: None,
+            yielded: 0,
+            pending: None,
+            warnings: Vec::new(),
+            invalid,
+        }
+    }
+
+    /// Stop after `max` reviews.
+    pub fn with_max_reviews(mut self, max: usize) -> Self {
+        self.max_reviews = Some(max);
+        self
+    }
+
+    /// Stop at the first review older than `time`. Only useful with
+    /// [`Filter::Recent`] and [`Filter::Updated`], which return newest reviews first;
+    /// the latter compares the last update time.
+    pub fn with_stop_before(mut self, time: SystemTime) -> Self {
+        self.stop_before = Some(time);
+        self
+    }
+
+    /// Summary of the first page, `None` until it is fetched.
+    pub fn summary(&self) -> Option<&QuerySummary> {
+        self.summary.as_ref()
+    }
+
+    /// Cursor of the next page, to resume later; `None` once all pages were fetched.
+    pub fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    /// Values dropped by lenient parsing from the pages fetched so far, empty otherwise.
+    pub fn warnings(&self) -> &[ParseWarning] {
+        &self.warnings
+    }
+
+    fn is_too_old(&self, review: &Review) -> bool {
+        let Some(stop) = self.stop_before else {
+            return false;
+        };
+
+        let time = match self.filter.filter {
+            Some(Filter::Updated) => review.updated_at(),
+            _ => review.created_at(),
+        };
+        time < stop
+    }
+
+    fn add_page(&mut self, res: Response<Reviews>) {
+        self.warnings.extend(res.warnings);
+        let page = res.data;
+        if self.summary.is_none() {
+            self.summary = Some(page.query_summary);
+        }
+
+        self.cursor = if page.reviews.is_empty() || self.seen_cursors.contains(&page.cursor) {
+            None
+        } else {
+            Some(page.cursor)
+        };
+        self.buffer.extend(page.reviews);
+    }
+}
+
+impl Stream for ReviewStream {
+    type Item = Result<Review, SteamErr>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if let Some(e) = this.invalid.take() {
+            return Poll::Ready(Some(Err(e)));
+        }
+
+        loop {
+            if this.max_reviews.is_some_and(|max| this.yielded >= max) {
+                return Poll::Ready(None);
+            }
+
+            if let Some(review) = this.buffer.pop_front() {
+                if this.is_too_old(&review) {
+                    this.buffer.clear();
+                    this.cursor = None;
+                    return Poll::Ready(None);
+                }
+                if !this.seen_reviews.insert(review.review_id.clone()) {
+                    continue;
+                }
+                this.yielded += 1;
+                return Poll::Ready(Some(Ok(review)));
+            }
+
+            if this.pending.is_none() {
+                let Some(cursor) = this.cursor.clone() else {
+                    return Poll::Ready(None);
+                };
+                this.seen_cursors.insert(cursor.clone());
+
+                let steam = this.steam.clone();
+                let app_id = this.app_id;
+                let filter = ReviewsFilter {
+                    cursor: Some(cursor),
+                    ..this.filter.clone()
+                };
+                this.pending = Some(Box::pin(async move {
+                    steam.fetch_reviews(&app_id, &filter, false).await
+                }));
+            }
+
+            let Some(pending) = this.pending.as_mut() else {
+                continue;
+            };
+            let page = match pending.as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(page) => page,
+            };
+            this.pending = None;
+
+            match page {
+                Ok(page) => this.add_page(page),
+                Err(e) => {
+                    // Keep the cursor, so the stream can be polled again to retry.
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
+
+fn timestamp(secs: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+fn minutes(minutes: u64) -> Duration {
+    Duration::from_secs(minutes * 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day_range(days: u16) -> ReviewsFilter {
+        ReviewsFilter {
+            day_range: Some(days),
+            ..Default::default()
+        }
+    }
+
+    fn num_per_page(num: u8) -> ReviewsFilter {
+        ReviewsFilter {
+            num_per_page: Some(num),
+            ..Default::default()
+        }
+    }
+
+    fn param(filter: &ReviewsFilter, key: &str) -> Option<String> {
+        filter
+            .to_url_params()
+            .into_iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v)
+    }
+
+    #[test]
+    fn validates_day_range() {
+        assert!(day_range(0).validate().is_err());
+        assert!(day_range(1).validate().is_ok());
+        assert!(day_range(365).validate().is_ok());
+        assert!(day_range(366).validate().is_err());
+    }
+
+    #[test]
+    fn validates_num_per_page() {
+        assert!(num_per_page(0).validate().is_err());
+        assert!(num_per_page(1).validate().is_ok());
+        assert!(num_per_page(100).validate().is_ok());
+        assert!(num_per_page(101).validate().is_err());
+    }
+
+    #[test]
+    fn validates_combinations() {
+        let recent = ReviewsFilter {
+            filter: Some(Filter::Recent),
+            ..day_range(30)
+        };
+        assert!(recent.validate().is_err());
+
+        let half_dates = ReviewsFilter {
+            start_date: Some(100),
+            ..Default::default()
+        };
+        assert!(half_dates.validate().is_err());
+
+        let reversed = ReviewsFilter {
+            start_date: Some(200),
+            end_date: Some(100),
+            ..Default::default()
+        };
+        assert!(reversed.validate().is_err());
+    }
+
+    #[test]
+    fn url_params_keep_values() {
+        // Values used to be raised to the maximum by `.max()`.
+        assert_eq!(param(&day_range(30), "day_range").as_deref(), Some("30"));
+        assert_eq!(param(&day_range(1), "day_range").as_deref(), Some("1"));
+        assert_eq!(param(&day_range(365), "day_range").as_deref(), Some("365"));
+        assert_eq!(
+            param(&num_per_page(20), "num_per_page").as_deref(),
+            Some("20")
+        );
+        assert_eq!(param(&ReviewsFilter::default(), "day_range"), None);
+    }
+
+    #[test]
+    fn builder_clamps() {
+        let build = |days| ReviewsFilterBuilder::new().with_day_range(days).build();
+        assert_eq!(build(0).unwrap().day_range, Some(1));
+        assert_eq!(build(1).unwrap().day_range, Some(1));
+        assert_eq!(build(365).unwrap().day_range, Some(365));
+        assert_eq!(build(366).unwrap().day_range, Some(365));
+
+        let build = |num| ReviewsFilterBuilder::new().with_num_per_page(num).build();
+        assert_eq!(build(0).unwrap().num_per_page, Some(1));
+        assert_eq!(build(101).unwrap().num_per_page, Some(100));
+    }
 }
@@ -0,0 +1,85 @@
+use std::io::{Read, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::steam::SteamErr;
+
+/// Version of the snapshot format written by this crate.
+///
+/// Bumped whenever a stored type changes in a way older readers can't handle.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Versioned archive of any store type, e.g. an [`AppDetails`](crate::app::AppDetails)
+/// response, that can be written to disk and read back later.
+///
+/// Fields are written under the crate's names (`app_id`, `x480`, ...),
+/// while the raw Steam names are still accepted when reading.
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Snapshot<T> {
+    pub version: u32,
+    /// Unix timestamp of when the data was captured
+    pub taken_at: u64,
+    pub data: T,
+}
+
+#[derive(Deserialize)]
+struct Header {
+    version: u32,
+}
+
+impl<T> Snapshot<T> {
+    /// Snapshot of `data` taken now.
+    pub fn new(data: T) -> Self {
+        let taken_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            taken_at,
+            data,
+        }
+    }
+
+    pub fn taken_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.taken_at)
+    }
+
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T: Serialize> Snapshot<T> {
+    pub fn to_json(&self) -> Result<String, SteamErr> {
+        serde_json::to_string(self).map_err(SteamErr::JsonError)
+    }
+
+    pub fn to_writer<W: Write>(&self, writer: W) -> Result<(), SteamErr> {
+        serde_json::to_writer(writer, self).map_err(SteamErr::JsonError)
+    }
+}
+
+impl<T: DeserializeOwned> Snapshot<T> {
+    /// Fails with [`SteamErr::UnsupportedSnapshotVersion`] for snapshots
+    /// written by a newer version of the crate.
+    pub fn from_json(json: &str) -> Result<Self, SteamErr> {
+        let header: Header = serde_json::from_str(json).map_err(SteamErr::JsonError)?;
+        if header.version == 0 || header.version > SNAPSHOT_VERSION {
+            return Err(SteamErr::UnsupportedSnapshotVersion(header.version));
+        }
+
+        serde_json::from_str(json).map_err(SteamErr::JsonError)
+    }
+
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, SteamErr> {
+        let mut json = String::new();
+        reader
+            .read_to_string(&mut json)
+            .map_err(SteamErr::IoError)?;
+
+        Self::from_json(&json)
+    }
+}
@@ -1,20 +1,39 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::num::ParseIntError;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::{stream, StreamExt};
 
 use thiserror::Error;
 use url::Url;
 
 use crate::app::{AppData, AppDetails, AppsIn, Genre, GenreData};
+use crate::country::CountryCode;
+use crate::lenient::{parse_lenient, ParseWarning};
 use crate::package::{DlcData, PackageData, PackageDetails};
 use crate::price::{AppPrice, Featured, FeaturedCategorie, FeaturedCategories, PriceData};
-use crate::review::{Reviews, ReviewsFilter};
+use crate::response::Response;
+use crate::review::{
+    PurchaseType, QuerySummary, ReviewStream, ReviewSummaries, Reviews, ReviewsFilter,
+};
+use crate::sync::{sync_reviews, ReviewCheckpoint, SyncReport};
+use crate::trailer::{StreamVariant, TrailerStream};
 use crate::types::Language;
 
+/// Simultaneous requests of [`Steam::review_summaries`].
+const SUMMARY_CONCURRENCY: usize = 8;
+
+/// Summaries by app and requested language, as `review_score_desc` is localized.
+type SummaryCache = HashMap<(u64, Option<Language>), (Instant, QuerySummary)>;
+
 /// Builder for Steam.
 pub struct SteamBuilder {
     language: Option<Language>,
     country_code: Option<String>,
     store_url: Url,
+    lenient: bool,
+    summary_ttl: Duration,
 }
 
 impl Default for SteamBuilder {
@@ -29,21 +48,26 @@ impl SteamBuilder {
             language: None,
             country_code: None,
             store_url: Url::parse("https://store.steampowered.com").unwrap(),
+            lenient: false,
+            summary_ttl: Duration::from_secs(60 * 60),
         }
     }
 
     pub fn build(self) -> Result<Steam, SteamErr> {
-        if let Some(cc) = &self.country_code {
-            if rust_iso3166::from_alpha2(cc).is_none() {
-                return Err(SteamErr::IncorrectCountryCode);
-            };
-        };
+        let country_code = self
+            .country_code
+            .as_deref()
+            .map(CountryCode::new)
+            .transpose()?;
 
         Ok(Steam {
             language: self.language,
-            country_code: self.country_code,
+            country_code,
             store_url: self.store_url,
             client: reqwest::Client::new(),
+            lenient: self.lenient,
+            summary_ttl: self.summary_ttl,
+            summaries: Arc::default(),
         })
     }
 
@@ -54,20 +78,52 @@ impl SteamBuilder {
         self
     }
 
+    /// Same as [`SteamBuilder::with_country_code`] with an already validated code.
+    pub fn with_country(mut self, country: CountryCode) -> Self {
+        self.country_code = Some(country.to_string());
+        self
+    }
+
     /// Language for localized strings.
     /// Takes English name of language (none of those fancy ISO distractions)
     pub fn with_language(mut self, language: &Language) -> Self {
         self.language = Some(language.clone());
         self
     }
+
+    /// Drop values with an unexpected shape instead of failing the request.
+    /// Dropped values are reported in [`Response::warnings`] by the `_raw` methods,
+    /// and in [`ReviewStream::warnings`], [`ReviewSummaries::warnings`]
+    /// and [`SyncReport::warnings`].
+    pub fn with_lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// How long [`Steam::review_summaries`] reuses a fetched summary, 1 hour by default.
+    /// Zero disables caching.
+    pub fn with_summary_ttl(mut self, ttl: Duration) -> Self {
+        self.summary_ttl = ttl;
+        self
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_store_url(mut self, store_url: Url) -> Self {
+        self.store_url = store_url;
+        self
+    }
 }
 
 /// API client for the Steam store
+#[derive(Clone)]
 pub struct Steam {
     language: Option<Language>,
-    country_code: Option<String>,
+    country_code: Option<CountryCode>,
     store_url: Url,
     client: reqwest::Client,
+    lenient: bool,
+    summary_ttl: Duration,
+    summaries: Arc<Mutex<SummaryCache>>,
 }
 
 /// Steam store API error types and error messages.
@@ -87,6 +143,20 @@ pub enum SteamErr {
     UrlError(url::ParseError),
     #[error("failed to parse id: {0}")]
     ParseIdError(ParseIntError),
+    #[error("invalid currency code {0}")]
+    UnknownCurrency(String),
+    #[error("unknown genre {0}")]
+    UnknownGenre(String),
+    #[error("unknown language {0}")]
+    UnknownLanguage(String),
+    #[error(transparent)]
+    IoError(std::io::Error),
+    #[error(transparent)]
+    JsonError(serde_json::Error),
+    #[error("invalid reviews filter: {0}")]
+    InvalidReviewsFilter(String),
+    #[error("snapshot version {0} is not supported")]
+    UnsupportedSnapshotVersion(u32),
 }
 
 impl Steam {
@@ -94,64 +164,152 @@ impl Steam {
         SteamBuilder::new()
     }
 
-    async fn send<T: for<'de> serde::Deserialize<'de>>(&self, mut url: Url) -> Result<T, SteamErr> {
+    /// Country prices and availability are requested for.
+    pub fn country(&self) -> Option<CountryCode> {
+        self.country_code
+    }
+
+    /// Copy of the client requesting prices for another country.
+    /// Shares the underlying connection pool, so it is cheap to create per request.
+    pub fn with_country(&self, country: CountryCode) -> Steam {
+        Steam {
+            country_code: Some(country),
+            ..self.clone()
+        }
+    }
+
+    /// Requests `url`. Without `raw` or lenient parsing the body is decoded
+    /// by reqwest and not kept, so decode errors stay `RequestError`.
+    async fn send<T: for<'de> serde::Deserialize<'de>>(
+        &self,
+        mut url: Url,
+        raw: bool,
+    ) -> Result<Response<T>, SteamErr> {
         if let Some(l) = &self.language {
             url.query_pairs_mut().append_pair("l", l.as_ref());
         }
 
         if let Some(cc) = &self.country_code {
-            url.query_pairs_mut().append_pair("cc", cc);
+            url.query_pairs_mut().append_pair("cc", cc.as_ref());
         }
 
         let res = self
             .client
-            .get(url)
+            .get(url.clone())
             .send()
             .await
             .map_err(SteamErr::RequestError)?;
 
         //res.error_for_status_ref().map_err(SteamErr::RequestError)?;
 
-        let data: T = res.json().await.map_err(SteamErr::RequestError)?;
+        let status = res.status();
+        let headers = res.headers().clone();
+
+        if !raw && !self.lenient {
+            let data: T = res.json().await.map_err(SteamErr::RequestError)?;
+            return Ok(Response {
+                data,
+                url,
+                status,
+                headers,
+                raw: String::new(),
+                warnings: Vec::new(),
+            });
+        }
 
-        Ok(data)
+        let raw = res.text().await.map_err(SteamErr::RequestError)?;
+
+        let (data, warnings): (T, _) = if self.lenient {
+            let (data, warnings) = parse_lenient(&raw)?;
+            let warnings = warnings
+                .into_iter()
+                .map(|w| ParseWarning {
+                    url: Some(url.to_string()),
+                    ..w
+                })
+                .collect();
+            (data, warnings)
+        } else {
+            let data = serde_json::from_str(&raw).map_err(SteamErr::JsonError)?;
+            (data, Vec::new())
+        };
+
+        Ok(Response {
+            data,
+            url,
+            status,
+            headers,
+            raw,
+            warnings,
+        })
     }
 
     /// Get featured page from the Steam store
     pub async fn featured(&self) -> Result<Featured, SteamErr> {
+        self.fetch_featured(false).await.map(|r| r.data)
+    }
+
+    /// Same as [`Steam::featured`], keeping the raw response.
+    pub async fn featured_raw(&self) -> Result<Response<Featured>, SteamErr> {
+        self.fetch_featured(true).await
+    }
+
+    async fn fetch_featured(&self, raw: bool) -> Result<Response<Featured>, SteamErr> {
         let url = self
             .store_url
             .join("api/featured/")
             .map_err(SteamErr::UrlError)?;
 
-        let data: Featured = self.send(url).await?;
+        let res: Response<Featured> = self.send(url, raw).await?;
 
-        if data.status != 1 {
+        if res.data.status != 1 {
             return Err(SteamErr::ResponseWithNoSuccess);
         }
 
-        Ok(data)
+        Ok(res)
     }
 
     /// Get list of genres
     pub async fn genres(&self) -> Result<Vec<Genre>, SteamErr> {
+        self.fetch_genres(false).await.map(|r| r.data)
+    }
+
+    /// Same as [`Steam::genres`], keeping the raw response.
+    pub async fn genres_raw(&self) -> Result<Response<Vec<Genre>>, SteamErr> {
+        self.fetch_genres(true).await
+    }
+
+    async fn fetch_genres(&self, raw: bool) -> Result<Response<Vec<Genre>>, SteamErr> {
         let url = self
             .store_url
             .join("api/getgenrelist/")
             .map_err(SteamErr::UrlError)?;
 
-        let data: GenreData = self.send(url).await?;
+        let res: Response<GenreData> = self.send(url, raw).await?;
 
-        if data.status != 1 {
+        if res.data.status != 1 {
             return Err(SteamErr::ResponseWithNoSuccess);
         }
 
-        Ok(data.genres)
+        Ok(res.map(|data| data.genres))
     }
 
     /// Get apps for genre, e.g. `action`, `rpg`
     /// Apps are divided by tabs, e.g. `topsellers` or `specials`
     pub async fn apps_in_genre(&self, genre: &str) -> Result<AppsIn, SteamErr> {
+        self.fetch_apps_in_genre(genre, false).await.map(|r| r.data)
+    }
+
+    /// Same as [`Steam::apps_in_genre`], keeping the raw response.
+    pub async fn apps_in_genre_raw(&self, genre: &str) -> Result<Response<AppsIn>, SteamErr> {
+        self.fetch_apps_in_genre(genre, true).await
+    }
+
+    async fn fetch_apps_in_genre(
+        &self,
+        genre: &str,
+        raw: bool,
+    ) -> Result<Response<AppsIn>, SteamErr> {
         let mut url = self
             .store_url
             .join("api/getappsingenre/")
@@ -159,18 +317,33 @@ impl Steam {
 
         url.query_pairs_mut().extend_pairs(vec![("genre", &genre)]);
 
-        let data: AppsIn = self.send(url).await?;
+        let res: Response<AppsIn> = self.send(url, raw).await?;
 
-        if data.status != 1 {
+        if res.data.status != 1 {
             return Err(SteamErr::ResponseWithNoSuccess);
         }
 
-        Ok(data)
+        Ok(res)
     }
 
     /// Get apps for category, e.g. `cat_comingsoon`, `cat_newreleases`
     /// Apps are divided by tabs, e.g. `topsellers` or `specials`
     pub async fn apps_in_category(&self, category: &str) -> Result<AppsIn, SteamErr> {
+        self.fetch_apps_in_category(category, false)
+            .await
+            .map(|r| r.data)
+    }
+
+    /// Same as [`Steam::apps_in_category`], keeping the raw response.
+    pub async fn apps_in_category_raw(&self, category: &str) -> Result<Response<AppsIn>, SteamErr> {
+        self.fetch_apps_in_category(category, true).await
+    }
+
+    async fn fetch_apps_in_category(
+        &self,
+        category: &str,
+        raw: bool,
+    ) -> Result<Response<AppsIn>, SteamErr> {
         let mut url = self
             .store_url
             .join("api/getappsincategory/")
@@ -179,35 +352,62 @@ impl Steam {
         url.query_pairs_mut()
             .extend_pairs(vec![("category", &category)]);
 
-        let data: AppsIn = self.send(url).await?;
+        let res: Response<AppsIn> = self.send(url, raw).await?;
 
-        if data.status != 1 {
+        if res.data.status != 1 {
             return Err(SteamErr::ResponseWithNoSuccess);
         }
 
-        Ok(data)
+        Ok(res)
     }
 
     /// Get featured categories with prices, e.g Specials, Top Sellers
     pub async fn featured_categories(
         &self,
     ) -> Result<HashMap<String, FeaturedCategorie>, SteamErr> {
+        self.fetch_featured_categories(false).await.map(|r| r.data)
+    }
+
+    /// Same as [`Steam::featured_categories`], keeping the raw response.
+    pub async fn featured_categories_raw(
+        &self,
+    ) -> Result<Response<HashMap<String, FeaturedCategorie>>, SteamErr> {
+        self.fetch_featured_categories(true).await
+    }
+
+    async fn fetch_featured_categories(
+        &self,
+        raw: bool,
+    ) -> Result<Response<HashMap<String, FeaturedCategorie>>, SteamErr> {
         let url = self
             .store_url
             .join("api/featuredcategories/")
             .map_err(SteamErr::UrlError)?;
 
-        let data: FeaturedCategories = self.send(url).await?;
+        let res: Response<FeaturedCategories> = self.send(url, raw).await?;
 
-        if data.status != 1 {
+        if res.data.status != 1 {
             return Err(SteamErr::ResponseWithNoSuccess);
         }
 
-        Ok(data.featured_categories)
+        Ok(res.map(|data| data.featured_categories))
     }
 
     /// Information about application packages (not bundles)
     pub async fn package(&self, pkg_id: &u64) -> Result<PackageDetails, SteamErr> {
+        self.fetch_package(pkg_id, false).await.map(|r| r.data)
+    }
+
+    /// Same as [`Steam::package`], keeping the raw response.
+    pub async fn package_raw(&self, pkg_id: &u64) -> Result<Response<PackageDetails>, SteamErr> {
+        self.fetch_package(pkg_id, true).await
+    }
+
+    async fn fetch_package(
+        &self,
+        pkg_id: &u64,
+        raw: bool,
+    ) -> Result<Response<PackageDetails>, SteamErr> {
         let id = pkg_id.to_string();
 
         let mut url = self
@@ -218,46 +418,203 @@ impl Steam {
         url.query_pairs_mut()
             .extend_pairs(vec![("packageids", &id)]);
 
-        let mut data: HashMap<String, PackageData> = self.send(url).await?;
+        let res: Response<HashMap<String, PackageData>> = self.send(url, raw).await?;
 
-        let data = match data.remove(&id) {
-            Some(data) => data,
-            None => return Err(SteamErr::IdNotFound(id)),
-        };
+        res.try_map(|mut data| {
+            let data = match data.remove(&id) {
+                Some(data) => data,
+                None => return Err(SteamErr::IdNotFound(id)),
+            };
 
-        if !data.success {
-            return Err(SteamErr::ResponseWithNoSuccess);
-        }
+            if !data.success {
+                return Err(SteamErr::ResponseWithNoSuccess);
+            }
 
-        match data.data {
-            Some(mut d) => {
-                d.pkg_id = *pkg_id;
-                Ok(d)
+            match data.data {
+                Some(mut d) => {
+                    d.pkg_id = *pkg_id;
+                    Ok(d)
+                }
+                None => Err(SteamErr::ResponseWithNoData),
             }
-            None => Err(SteamErr::ResponseWithNoData),
-        }
+        })
     }
 
     /// Get reviews for application with filters
     pub async fn reviews(&self, app_id: &u64, filter: &ReviewsFilter) -> Result<Reviews, SteamErr> {
+        self.fetch_reviews(app_id, filter, false)
+            .await
+            .map(|r| r.data)
+    }
+
+    /// Same as [`Steam::reviews`], keeping the raw response.
+    pub async fn reviews_raw(
+        &self,
+        app_id: &u64,
+        filter: &ReviewsFilter,
+    ) -> Result<Response<Reviews>, SteamErr> {
+        self.fetch_reviews(app_id, filter, true).await
+    }
+
+    pub(crate) async fn fetch_reviews(
+        &self,
+        app_id: &u64,
+        filter: &ReviewsFilter,
+        raw: bool,
+    ) -> Result<Response<Reviews>, SteamErr> {
+        filter.validate()?;
+        self.request_reviews(app_id, filter.to_url_params(), raw)
+            .await
+    }
+
+    /// Sends a reviews request with already validated parameters.
+    async fn request_reviews(
+        &self,
+        app_id: &u64,
+        params: Vec<(&str, String)>,
+        raw: bool,
+    ) -> Result<Response<Reviews>, SteamErr> {
         let mut url = self
             .store_url
             .join(&format!("appreviews/{}", app_id))
             .map_err(SteamErr::UrlError)?;
 
-        url.query_pairs_mut().extend_pairs(filter.to_url_params());
+        url.query_pairs_mut().extend_pairs(params);
 
-        let data: Reviews = self.send(url).await?;
+        let mut res: Response<Reviews> = self.send(url, raw).await?;
 
-        if data.success != 1 {
+        if res.data.success != 1 {
             return Err(SteamErr::ResponseWithNoSuccess);
         }
 
-        Ok(data)
+        res.data.app_id = *app_id;
+        Ok(res)
+    }
+
+    /// Review summaries of many apps, e.g. for "Very Positive (12,345)" badges.
+    ///
+    /// Counts cover every language and purchase type, no reviews are fetched.
+    /// Up to 8 apps are requested at once and summaries are cached per app and
+    /// client language for the duration set by [`SteamBuilder::with_summary_ttl`],
+    /// as `review_score_desc` is localized. Apps that fail are reported
+    /// in [`ReviewSummaries::failed`] without failing the others.
+    pub async fn review_summaries<I>(&self, app_ids: I) -> ReviewSummaries
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        let mut out = ReviewSummaries::default();
+        let mut missing = Vec::new();
+
+        {
+            let mut cache = self.summaries.lock().ok();
+            if let Some(cache) = cache.as_mut() {
+                cache.retain(|_, (at, _)| at.elapsed() < self.summary_ttl);
+            }
+            for app_id in app_ids {
+                if out.summaries.contains_key(&app_id) || missing.contains(&app_id) {
+                    continue;
+                }
+                let key = (app_id, self.language.clone());
+                match cache.as_ref().and_then(|c| c.get(&key)) {
+                    Some((_, summary)) => {
+                        out.summaries.insert(app_id, summary.clone());
+                    }
+                    None => missing.push(app_id),
+                }
+            }
+        }
+
+        let results: Vec<(u64, Result<Response<QuerySummary>, SteamErr>)> = stream::iter(missing)
+            .map(|app_id| async move { (app_id, self.review_summary(app_id).await) })
+            .buffer_unordered(SUMMARY_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut cache = self.summaries.lock().ok();
+        for (app_id, res) in results {
+            match res {
+                Ok(res) => {
+                    let summary = res.data;
+                    out.warnings.extend(res.warnings);
+                    if let Some(cache) = cache.as_mut().filter(|_| !self.summary_ttl.is_zero()) {
+                        let key = (app_id, self.language.clone());
+                        cache.insert(key, (Instant::now(), summary.clone()));
+                    }
+                    out.summaries.insert(app_id, summary);
+                }
+                Err(e) => {
+                    out.failed.insert(app_id, e);
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Forgets summaries cached by [`Steam::review_summaries`],
+    /// shared with clients made by [`Steam::with_country`].
+    pub fn clear_summary_cache(&self) {
+        if let Ok(mut cache) = self.summaries.lock() {
+            cache.clear();
+        }
+    }
+
+    async fn review_summary(&self, app_id: u64) -> Result<Response<QuerySummary>, SteamErr> {
+        let filter = ReviewsFilter {
+            language: Some(Language::All),
+            purchase_type: Some(PurchaseType::All),
+            ..Default::default()
+        };
+
+        // num_per_page=0 returns the summary alone, filters only allow 1 to 100
+        let mut params = filter.to_url_params();
+        params.push(("num_per_page", "0".to_owned()));
+
+        self.request_reviews(&app_id, params, false)
+            .await
+            .map(|r| r.map(|d| d.query_summary))
+    }
+
+    /// Reviews for application across all pages, starting at `filter.cursor`
+    /// or the first page. Pages of 100 reviews are requested unless
+    /// `filter.num_per_page` says otherwise.
+    pub fn reviews_stream(&self, app_id: &u64, filter: &ReviewsFilter) -> ReviewStream {
+        ReviewStream::new(self.clone(), *app_id, filter.clone())
+    }
+
+    /// Reviews created or edited since `checkpoint`, which is advanced on success.
+    /// `filter` narrows the reviews, its sort order and cursor are ignored.
+    pub async fn sync_reviews(
+        &self,
+        checkpoint: &mut ReviewCheckpoint,
+        filter: &ReviewsFilter,
+    ) -> Result<SyncReport, SteamErr> {
+        sync_reviews(self, checkpoint, filter, None).await
+    }
+
+    /// Like [`Steam::sync_reviews`], but reads every review to also report
+    /// which of the `known` review ids were removed.
+    /// Reviews not in `known` are reported as new.
+    pub async fn reconcile_reviews(
+        &self,
+        checkpoint: &mut ReviewCheckpoint,
+        filter: &ReviewsFilter,
+        known: &BTreeSet<String>,
+    ) -> Result<SyncReport, SteamErr> {
+        sync_reviews(self, checkpoint, filter, Some(known)).await
     }
 
     /// Information about application DLCs
     pub async fn dlc(&self, app_id: &u64) -> Result<DlcData, SteamErr> {
+        self.fetch_dlc(app_id, false).await.map(|r| r.data)
+    }
+
+    /// Same as [`Steam::dlc`], keeping the raw response.
+    pub async fn dlc_raw(&self, app_id: &u64) -> Result<Response<DlcData>, SteamErr> {
+        self.fetch_dlc(app_id, true).await
+    }
+
+    async fn fetch_dlc(&self, app_id: &u64, raw: bool) -> Result<Response<DlcData>, SteamErr> {
         let id = app_id.to_string();
 
         let mut url = self
@@ -267,20 +624,29 @@ impl Steam {
 
         url.query_pairs_mut().extend_pairs(vec![("appid", &id)]);
 
-        let data: DlcData = self.send(url).await?;
+        let res: Response<DlcData> = self.send(url, raw).await?;
 
-        if data.status != 1 {
+        if res.data.status != 1 {
             return Err(SteamErr::ResponseWithNoSuccess);
         }
 
-        match data.dlc {
-            Some(_) => Ok(data),
+        match res.data.dlc {
+            Some(_) => Ok(res),
             None => Err(SteamErr::ResponseWithNoData),
         }
     }
 
     /// Detailed information about application
     pub async fn app(&self, app_id: &u64) -> Result<AppDetails, SteamErr> {
+        self.fetch_app(app_id, false).await.map(|r| r.data)
+    }
+
+    /// Same as [`Steam::app`], keeping the raw response.
+    pub async fn app_raw(&self, app_id: &u64) -> Result<Response<AppDetails>, SteamErr> {
+        self.fetch_app(app_id, true).await
+    }
+
+    async fn fetch_app(&self, app_id: &u64, raw: bool) -> Result<Response<AppDetails>, SteamErr> {
         let id = app_id.to_string();
 
         let mut url = self
@@ -290,21 +656,23 @@ impl Steam {
 
         url.query_pairs_mut().extend_pairs(vec![("appids", &id)]);
 
-        let mut data: HashMap<String, AppData> = self.send(url).await?;
+        let res: Response<HashMap<String, AppData>> = self.send(url, raw).await?;
 
-        let data = match data.remove(&id) {
-            Some(data) => data,
-            None => return Err(SteamErr::IdNotFound(id)),
-        };
+        res.try_map(|mut data| {
+            let data = match data.remove(&id) {
+                Some(data) => data,
+                None => return Err(SteamErr::IdNotFound(id)),
+            };
 
-        if !data.success {
-            return Err(SteamErr::ResponseWithNoSuccess);
-        }
+            if !data.success {
+                return Err(SteamErr::ResponseWithNoSuccess);
+            }
 
-        match data.data {
-            Some(d) => Ok(d),
-            None => Err(SteamErr::ResponseWithNoData),
-        }
+            match data.data {
+                Some(d) => Ok(d),
+                None => Err(SteamErr::ResponseWithNoData),
+            }
+        })
     }
 
     /// Get price overview for a multiple applications
@@ -312,6 +680,22 @@ impl Steam {
         &self,
         app_ids: I,
     ) -> Result<Vec<AppPrice>, SteamErr> {
+        self.fetch_price(app_ids, false).await.map(|r| r.data)
+    }
+
+    /// Same as [`Steam::price`], keeping the raw response.
+    pub async fn price_raw<I: IntoIterator<Item = u64>>(
+        &self,
+        app_ids: I,
+    ) -> Result<Response<Vec<AppPrice>>, SteamErr> {
+        self.fetch_price(app_ids, true).await
+    }
+
+    async fn fetch_price<I: IntoIterator<Item = u64>>(
+        &self,
+        app_ids: I,
+        raw: bool,
+    ) -> Result<Response<Vec<AppPrice>>, SteamErr> {
         let ids: Vec<String> = app_ids.into_iter().map(|v| v.to_string()).collect();
 
         let mut url = self
@@ -324,21 +708,206 @@ impl Steam {
             ("filters", &"price_overview".to_owned()),
         ]);
 
-        let data: HashMap<String, PriceData> = self.send(url).await?;
+        let res: Response<HashMap<String, PriceData>> = self.send(url, raw).await?;
 
-        let mut out = Vec::with_capacity(data.len());
+        res.try_map(|data| {
+            let mut out = Vec::with_capacity(data.len());
 
-        for (k, v) in data {
-            if v.success {
-                if let Some(price) = v.data {
-                    let mut p = price.price_overview;
-                    p.app_id = k.parse().map_err(SteamErr::ParseIdError)?;
-                    out.push(p);
+            for (k, v) in data {
+                if v.success {
+                    if let Some(price) = v.data {
+                        let mut p = price.price_overview;
+                        p.app_id = k.parse().map_err(SteamErr::ParseIdError)?;
+                        out.push(p);
+                    }
                 }
             }
+
+            out.shrink_to_fit();
+            Ok(out)
+        })
+    }
+
+    /// Get price overview for multiple applications in each of `countries`
+    pub async fn price_matrix<I, C>(
+        &self,
+        app_ids: I,
+        countries: C,
+    ) -> Result<HashMap<CountryCode, Vec<AppPrice>>, SteamErr>
+    where
+        I: IntoIterator<Item = u64>,
+        C: IntoIterator<Item = CountryCode>,
+    {
+        let ids: Vec<u64> = app_ids.into_iter().collect();
+        let mut out = HashMap::new();
+
+        for country in countries {
+            let prices = self.with_country(country).price(ids.clone()).await?;
+            out.insert(country, prices);
         }
 
-        out.shrink_to_fit();
         Ok(out)
     }
+
+    /// Fetch the manifest of a trailer stream and list its renditions
+    pub async fn trailer_variants(
+        &self,
+        stream: &TrailerStream,
+    ) -> Result<Vec<StreamVariant>, SteamErr> {
+        let manifest = self
+            .client
+            .get(&stream.manifest_url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(SteamErr::RequestError)?
+            .text()
+            .await
+            .map_err(SteamErr::RequestError)?;
+
+        Ok(stream.parse_manifest(&manifest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::mock;
+    use crate::snapshot::Snapshot;
+
+    #[tokio::test]
+    async fn reviews_keep_app_id_through_snapshots() {
+        let url =
+            mock::serve(|_| mock::reviews_page(vec![mock::review("1", 100, 100)], "AoJ")).await;
+        let steam = SteamBuilder::new().with_store_url(url).build().unwrap();
+
+        let reviews = steam
+            .reviews(&570, &ReviewsFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(reviews.app_id, 570);
+
+        let json = Snapshot::new(reviews.clone()).to_json().unwrap();
+        let snapshot = Snapshot::<Reviews>::from_json(&json).unwrap();
+        assert_eq!(snapshot.data.app_id, 570);
+        assert_eq!(snapshot.into_inner(), reviews);
+    }
+
+    #[tokio::test]
+    async fn lenient_warnings_reach_streams_summaries_and_sync() {
+        let url = mock::serve(|target| {
+            if target.contains("num_per_page=0") {
+                return r#"{"success": 1, "cursor": "*",
+                    "query_summary": {"num_reviews": 0, "total_reviews": "many"},
+                    "reviews": []}"#
+                    .to_owned();
+            }
+            let mut review = mock::review("1", 100, 100);
+            review["comment_count"] = "many".into();
+            match target.contains("cursor=*") {
+                true => mock::reviews_page(vec![review], "next"),
+                false => mock::reviews_page(vec![], "next"),
+            }
+        })
+        .await;
+        let steam = SteamBuilder::new()
+            .with_lenient(true)
+            .with_store_url(url)
+            .build()
+            .unwrap();
+
+        let mut stream = steam.reviews_stream(&570, &ReviewsFilter::default());
+        let review = stream.next().await.unwrap().unwrap();
+        assert_eq!(review.comment_count, None);
+        assert_eq!(stream.warnings().len(), 1);
+        assert_eq!(stream.warnings()[0].path, "reviews[0].comment_count");
+        assert!(stream.warnings()[0].url.is_some());
+
+        let summaries = steam.review_summaries([570]).await;
+        assert_eq!(summaries.summaries[&570].total_reviews, None);
+        assert_eq!(summaries.warnings.len(), 1);
+        assert_eq!(summaries.warnings[0].path, "query_summary.total_reviews");
+
+        let mut checkpoint = ReviewCheckpoint::new(570);
+        let report = steam
+            .sync_reviews(&mut checkpoint, &ReviewsFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(report.new.len(), 1);
+        assert_eq!(report.warnings.len(), 1);
+    }
+
+    /// Store counting summary requests, answering in the requested language.
+    async fn summary_store(hits: Arc<AtomicUsize>) -> Url {
+        mock::serve(move |target| {
+            hits.fetch_add(1, Ordering::SeqCst);
+            let desc = match target.contains("l=german") {
+                true => "Sehr positiv",
+                false => "Very Positive",
+            };
+            serde_json::json!({
+                "success": 1,
+                "cursor": "*",
+                "query_summary": {"num_reviews": 0, "review_score_desc": desc},
+                "reviews": [],
+            })
+            .to_string()
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn summaries_are_cached_per_language() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let url = summary_store(hits.clone()).await;
+        let steam = SteamBuilder::new()
+            .with_language(&Language::English)
+            .with_store_url(url)
+            .build()
+            .unwrap();
+        let german = Steam {
+            language: Some(Language::German),
+            ..steam.clone()
+        };
+        let desc = |s: &ReviewSummaries| s.summaries[&570].review_score_desc.clone();
+
+        let english = steam.review_summaries([570]).await;
+        assert_eq!(desc(&english).as_deref(), Some("Very Positive"));
+        let cached = steam.review_summaries([570, 570]).await;
+        assert_eq!(desc(&cached), desc(&english));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        let localized = german.review_summaries([570]).await;
+        assert_eq!(desc(&localized).as_deref(), Some("Sehr positiv"));
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+        steam.clear_summary_cache();
+        assert!(german.summaries.lock().unwrap().is_empty());
+        steam.review_summaries([570]).await;
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn expired_summaries_are_evicted() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let url = summary_store(hits.clone()).await;
+        let steam = SteamBuilder::new()
+            .with_summary_ttl(Duration::from_millis(50))
+            .with_store_url(url)
+            .build()
+            .unwrap();
+
+        steam.review_summaries([570, 730]).await;
+        assert_eq!(steam.summaries.lock().unwrap().len(), 2);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let none = steam.review_summaries([]).await;
+        assert!(none.summaries.is_empty());
+        assert!(steam.summaries.lock().unwrap().is_empty());
+
+        steam.review_summaries([570]).await;
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
 }
@@ -0,0 +1,270 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, UNIX_EPOCH};
+
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::lenient::ParseWarning;
+use crate::review::{Filter, Review, ReviewsFilter};
+use crate::steam::{Steam, SteamErr};
+
+/// Where the last review sync of an app stopped.
+/// Stays small however many reviews the app has.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct ReviewCheckpoint {
+    pub app_id: u64,
+    /// Newest `timestamp_updated` seen, Unix timestamp
+    pub last_updated: u64,
+    /// Reviews updated exactly at `last_updated`, already seen
+    pub last_review_ids: BTreeSet<String>,
+}
+
+impl ReviewCheckpoint {
+    /// Checkpoint of an app never synced before.
+    pub fn new(app_id: u64) -> Self {
+        ReviewCheckpoint {
+            app_id,
+            ..Default::default()
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SteamErr> {
+        let data = fs::read(path).map_err(SteamErr::IoError)?;
+        serde_json::from_slice(&data).map_err(SteamErr::JsonError)
+    }
+
+    /// Writes to a temporary file first, so a crash never leaves a broken checkpoint.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SteamErr> {
+        let path = path.as_ref();
+        let tmp = path.with_extension("tmp");
+
+        let data = serde_json::to_vec(self).map_err(SteamErr::JsonError)?;
+        fs::write(&tmp, data).map_err(SteamErr::IoError)?;
+        fs::rename(&tmp, path).map_err(SteamErr::IoError)
+    }
+}
+
+/// Reviews that changed since the checkpoint.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct SyncReport {
+    /// Reviews not seen before
+    pub new: Vec<Review>,
+    /// Known reviews updated since the checkpoint
+    pub edited: Vec<Review>,
+    /// Ids of known reviews Steam no longer returns,
+    /// only filled by [`Steam::reconcile_reviews`].
+    pub removed: Vec<String>,
+    /// Values dropped by lenient parsing, empty otherwise
+    pub warnings: Vec<ParseWarning>,
+}
+
+impl SyncReport {
+    pub fn is_empty(&self) -> bool {
+        self.new.is_empty() && self.edited.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Fetches reviews changed since `checkpoint`, advancing it only on success.
+///
+/// Incremental runs read [`Filter::Updated`] pages until they reach reviews older
+/// than the checkpoint, telling new from edited reviews by creation time.
+/// Full runs, with the ids the caller already has as `known`, read every review
+/// by [`Filter::Recent`], which also reveals removed reviews.
+pub(crate) async fn sync_reviews(
+    steam: &Steam,
+    checkpoint: &mut ReviewCheckpoint,
+    filter: &ReviewsFilter,
+    known: Option<&BTreeSet<String>>,
+) -> Result<SyncReport, SteamErr> {
+    let full = known.is_some();
+    let filter = ReviewsFilter {
+        filter: Some(if full {
+            Filter::Recent
+        } else {
+            Filter::Updated
+        }),
+        cursor: None,
+        day_range: None,
+        ..filter.clone()
+    };
+
+    let mut reviews = steam.reviews_stream(&checkpoint.app_id, &filter);
+    if !full {
+        reviews =
+            reviews.with_stop_before(UNIX_EPOCH + Duration::from_secs(checkpoint.last_updated));
+    }
+
+    let mut report = SyncReport::default();
+    let mut seen = BTreeSet::new();
+    let mut last_updated = checkpoint.last_updated;
+    let mut last_review_ids = checkpoint.last_review_ids.clone();
+
+    while let Some(review) = reviews.next().await {
+        let review = review?;
+        let id = review.review_id.clone();
+
+        match review.timestamp_updated.cmp(&last_updated) {
+            Ordering::Greater => {
+                last_updated = review.timestamp_updated;
+                last_review_ids = BTreeSet::from([id.clone()]);
+            }
+            Ordering::Equal => {
+                last_review_ids.insert(id.clone());
+            }
+            Ordering::Less => {}
+        }
+
+        let synced = |timestamp: u64| match timestamp.cmp(&checkpoint.last_updated) {
+            Ordering::Less => true,
+            Ordering::Equal => checkpoint.last_review_ids.contains(&id),
+            Ordering::Greater => false,
+        };
+        let unchanged = synced(review.timestamp_updated);
+        let existed = match known {
+            Some(known) => known.contains(&id),
+            None => synced(review.timestamp_created),
+        };
+        if full {
+            seen.insert(id);
+        }
+
+        match (existed, unchanged) {
+            (true, true) => {}
+            (true, false) => report.edited.push(review),
+            (false, _) => report.new.push(review),
+        }
+    }
+
+    report.warnings = reviews.warnings().to_vec();
+    if let Some(known) = known {
+        report.removed = known.difference(&seen).cloned().collect();
+    }
+    checkpoint.last_updated = last_updated;
+    checkpoint.last_review_ids = last_review_ids;
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::mock;
+    use crate::steam::SteamBuilder;
+
+    /// Store answering every review query with `reviews` on the first page.
+    async fn steam(reviews: Vec<Value>) -> Steam {
+        let url = mock::serve(move |target| match target.contains("cursor=*") {
+            true => mock::reviews_page(reviews.clone(), "next"),
+            false => mock::reviews_page(vec![], "next"),
+        })
+        .await;
+        SteamBuilder::new().with_store_url(url).build().unwrap()
+    }
+
+    fn ids(reviews: &[Review]) -> Vec<&str> {
+        reviews.iter().map(|r| r.review_id.as_str()).collect()
+    }
+
+    fn checkpoint(last_updated: u64, ids: &[&str]) -> ReviewCheckpoint {
+        ReviewCheckpoint {
+            app_id: 570,
+            last_updated,
+            last_review_ids: ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+
+    /// Newest update first, as `filter=updated` returns them.
+    fn reviews() -> Vec<Value> {
+        vec![
+            mock::review("created", 1100, 1100),
+            mock::review("edited", 500, 1100),
+            mock::review("created_at_checkpoint", 1000, 1000),
+            mock::review("seen", 900, 1000),
+            mock::review("old", 400, 900),
+        ]
+    }
+
+    #[tokio::test]
+    async fn classifies_new_edited_and_unchanged() {
+        let steam = steam(reviews()).await;
+        let mut checkpoint = checkpoint(1000, &["seen"]);
+
+        let report = steam
+            .sync_reviews(&mut checkpoint, &ReviewsFilter::default())
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&report.new), ["created", "created_at_checkpoint"]);
+        assert_eq!(ids(&report.edited), ["edited"]);
+        assert!(report.removed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn checkpoint_keeps_only_newest_timestamp() {
+        let steam = steam(reviews()).await;
+        let mut checkpoint = checkpoint(1000, &["seen"]);
+
+        steam
+            .sync_reviews(&mut checkpoint, &ReviewsFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(checkpoint, self::checkpoint(1100, &["created", "edited"]));
+
+        // Nothing changed since, the next run reports nothing.
+        let report = steam
+            .sync_reviews(&mut checkpoint, &ReviewsFilter::default())
+            .await
+            .unwrap();
+        assert!(report.is_empty());
+        assert_eq!(checkpoint.last_updated, 1100);
+    }
+
+    #[tokio::test]
+    async fn first_sync_reports_everything_as_new() {
+        let steam = steam(reviews()).await;
+        let mut checkpoint = ReviewCheckpoint::new(570);
+
+        let report = steam
+            .sync_reviews(&mut checkpoint, &ReviewsFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(report.new.len(), 5);
+        assert!(report.edited.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reconcile_reports_removed() {
+        let steam = steam(reviews()).await;
+        let mut checkpoint = checkpoint(1000, &["seen"]);
+        let known = ["edited", "seen", "old", "deleted"]
+            .map(str::to_owned)
+            .into();
+
+        let report = steam
+            .reconcile_reviews(&mut checkpoint, &ReviewsFilter::default(), &known)
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&report.new), ["created", "created_at_checkpoint"]);
+        assert_eq!(ids(&report.edited), ["edited"]);
+        assert_eq!(report.removed, ["deleted"]);
+        assert_eq!(checkpoint.last_updated, 1100);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = std::env::temp_dir().join(format!("checkpoint-{}.json", std::process::id()));
+        let checkpoint = checkpoint(1100, &["a", "b"]);
+
+        checkpoint.save(&path).unwrap();
+        let loaded = ReviewCheckpoint::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, checkpoint);
+    }
+}
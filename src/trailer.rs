@@ -0,0 +1,307 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::app::Movie;
+use crate::html::attribute;
+
+/// Adaptive streaming protocol of a trailer.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum StreamProtocol {
+    Hls,
+    Dash,
+}
+
+/// Video codec of a trailer stream.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum VideoCodec {
+    H264,
+    Av1,
+}
+
+/// Adaptive stream of a trailer, described by a manifest.
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct TrailerStream {
+    pub protocol: StreamProtocol,
+    pub codec: VideoCodec,
+    pub manifest_url: String,
+}
+
+/// One rendition listed in a stream manifest.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct StreamVariant {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// RFC 6381 codec strings, e.g. `avc1.64001f` or `mp4a.40.2`
+    pub codecs: Vec<String>,
+    /// Bits per second
+    pub bandwidth: Option<u64>,
+    /// Media playlist of HLS variants or `BaseURL` of DASH representations,
+    /// as written in the manifest, see [`TrailerStream::variant_url`].
+    pub uri: Option<String>,
+}
+
+impl Movie {
+    /// Adaptive streams of the trailer, AV1 first.
+    pub fn streams(&self) -> Vec<TrailerStream> {
+        use StreamProtocol::*;
+        use VideoCodec::*;
+
+        [
+            (Dash, Av1, &self.dash_av1),
+            (Dash, H264, &self.dash_h264),
+            (Hls, H264, &self.hls_h264),
+        ]
+        .into_iter()
+        .filter_map(|(protocol, codec, url)| {
+            url.as_ref().map(|url| TrailerStream {
+                protocol,
+                codec,
+                manifest_url: url.to_owned(),
+            })
+        })
+        .collect()
+    }
+
+    /// URL of a progressive download, highest quality first, if any.
+    pub fn file_url(&self) -> Option<&str> {
+        self.mp4
+            .as_ref()
+            .or(self.webm.as_ref())
+            .map(|f| f.max.as_str())
+    }
+}
+
+impl TrailerStream {
+    /// Parses the manifest text fetched from `manifest_url`.
+    pub fn parse_manifest(&self, manifest: &str) -> Vec<StreamVariant> {
+        match self.protocol {
+            StreamProtocol::Hls => parse_hls(manifest),
+            StreamProtocol::Dash => parse_dash(manifest),
+        }
+    }
+
+    /// Absolute URL of a variant's `uri`, resolved against `manifest_url`.
+    pub fn variant_url(&self, variant: &StreamVariant) -> Option<String> {
+        let uri = variant.uri.as_ref()?;
+        Url::parse(&self.manifest_url)
+            .and_then(|base| base.join(uri))
+            .ok()
+            .map(String::from)
+    }
+}
+
+/// Reads `#EXT-X-STREAM-INF` entries of an HLS master playlist.
+fn parse_hls(playlist: &str) -> Vec<StreamVariant> {
+    let mut out = Vec::new();
+    let mut lines = playlist.lines().map(str::trim);
+
+    while let Some(line) = lines.next() {
+        let Some(attrs) = line.strip_prefix("#EXT-X-STREAM-INF:") else {
+            continue;
+        };
+
+        let mut variant = StreamVariant::default();
+        for (key, value) in hls_attributes(attrs) {
+            match key {
+                "BANDWIDTH" => variant.bandwidth = value.parse().ok(),
+                "RESOLUTION" => {
+                    if let Some((w, h)) = value.split_once('x') {
+                        variant.width = w.parse().ok();
+                        variant.height = h.parse().ok();
+                    }
+                }
+                "CODECS" => variant.codecs = split_codecs(value),
+                _ => {}
+            }
+        }
+        variant.uri = lines
+            .by_ref()
+            .find(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(str::to_owned);
+
+        out.push(variant);
+    }
+
+    out
+}
+
+/// Splits `KEY=value,KEY="quoted, value"` attribute lists.
+fn hls_attributes(attrs: &str) -> Vec<(&str, &str)> {
+    let mut out = Vec::new();
+    let mut rest = attrs;
+
+    while let Some((key, tail)) = rest.split_once('=') {
+        let (value, tail) = match tail.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"').unwrap_or(quoted.len());
+                (&quoted[..end], quoted.get(end + 1..).unwrap_or_default())
+            }
+            None => tail.split_once(',').unwrap_or((tail, "")),
+        };
+        out.push((key.trim(), value));
+        rest = tail.trim_start_matches(',');
+    }
+
+    out
+}
+
+/// Reads video `Representation`s of a DASH manifest,
+/// inheriting codecs from their `AdaptationSet`.
+fn parse_dash(mpd: &str) -> Vec<StreamVariant> {
+    let mut out: Vec<StreamVariant> = Vec::new();
+    let mut set_codecs: Option<String> = None;
+    let mut set_video = true;
+    let mut in_representation = false;
+
+    for tag in mpd.split('<').skip(1) {
+        let (tag, text) = tag.split_once('>').unwrap_or((tag, ""));
+
+        if tag.starts_with("/Representation") {
+            in_representation = false;
+        } else if tag.starts_with("BaseURL") {
+            if let Some(variant) = out.last_mut().filter(|_| in_representation) {
+                variant.uri = Some(text.trim().to_owned()).filter(|t| !t.is_empty());
+            }
+        } else if tag.starts_with("AdaptationSet") {
+            set_codecs = attribute(tag, "codecs");
+            set_video = attribute(tag, "mimetype")
+                .or_else(|| attribute(tag, "contenttype"))
+                .is_none_or(|m| m.starts_with("video"));
+        } else if tag.starts_with("Representation") {
+            let video = attribute(tag, "mimetype").map_or(set_video, |m| m.starts_with("video"));
+            in_representation = video && !tag.ends_with('/');
+            if !video {
+                continue;
+            }
+            out.push(StreamVariant {
+                width: attribute(tag, "width").and_then(|v| v.parse().ok()),
+                height: attribute(tag, "height").and_then(|v| v.parse().ok()),
+                codecs: attribute(tag, "codecs")
+                    .or_else(|| set_codecs.clone())
+                    .map(|c| split_codecs(&c))
+                    .unwrap_or_default(),
+                bandwidth: attribute(tag, "bandwidth").and_then(|v| v.parse().ok()),
+                uri: None,
+            });
+        }
+    }
+
+    out
+}
+
+fn split_codecs(codecs: &str) -> Vec<String> {
+    codecs
+        .split(',')
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream(protocol: StreamProtocol, manifest_url: &str) -> TrailerStream {
+        TrailerStream {
+            protocol,
+            codec: VideoCodec::H264,
+            manifest_url: manifest_url.to_owned(),
+        }
+    }
+
+    #[test]
+    fn hls_master_playlist() {
+        let playlist = "#EXTM3U
+#EXT-X-VERSION:3
+#EXT-X-STREAM-INF:BANDWIDTH=1280000,RESOLUTION=640x360,CODECS=\"avc1.4d401e, mp4a.40.2\"
+360p/index.m3u8
+
+#EXT-X-STREAM-INF:RESOLUTION=1920x1080,BANDWIDTH=5000000,CODECS=\"avc1.640028,mp4a.40.2\"
+/media/1080p/index.m3u8
+";
+        let stream = stream(
+            StreamProtocol::Hls,
+            "https://video.akamai.steamstatic.com/store_trailers/1/hls_264_master.m3u8",
+        );
+        let variants = stream.parse_manifest(playlist);
+
+        assert_eq!(
+            variants,
+            [
+                StreamVariant {
+                    width: Some(640),
+                    height: Some(360),
+                    codecs: vec!["avc1.4d401e".to_owned(), "mp4a.40.2".to_owned()],
+                    bandwidth: Some(1_280_000),
+                    uri: Some("360p/index.m3u8".to_owned()),
+                },
+                StreamVariant {
+                    width: Some(1920),
+                    height: Some(1080),
+                    codecs: vec!["avc1.640028".to_owned(), "mp4a.40.2".to_owned()],
+                    bandwidth: Some(5_000_000),
+                    uri: Some("/media/1080p/index.m3u8".to_owned()),
+                },
+            ]
+        );
+        assert_eq!(
+            stream.variant_url(&variants[0]).as_deref(),
+            Some("https://video.akamai.steamstatic.com/store_trailers/1/360p/index.m3u8")
+        );
+        assert_eq!(
+            stream.variant_url(&variants[1]).as_deref(),
+            Some("https://video.akamai.steamstatic.com/media/1080p/index.m3u8")
+        );
+    }
+
+    #[test]
+    fn dash_manifest() {
+        let mpd = r#"<?xml version="1.0"?>
+<MPD xmlns="urn:mpeg:dash:schema:mpd:2011">
+  <Period>
+    <AdaptationSet mimeType="video/mp4" codecs="av01.0.08M.08">
+      <Representation id="1" bandwidth="900000" width="1280" height="720">
+        <BaseURL>720p/video.mp4</BaseURL>
+      </Representation>
+      <Representation id="2" bandwidth="2500000" width="1920" height="1080" codecs="av01.0.09M.08"/>
+    </AdaptationSet>
+    <AdaptationSet mimeType="audio/mp4" codecs="mp4a.40.2">
+      <Representation id="3" bandwidth="128000">
+        <BaseURL>audio.mp4</BaseURL>
+      </Representation>
+    </AdaptationSet>
+  </Period>
+</MPD>"#;
+        let stream = stream(
+            StreamProtocol::Dash,
+            "https://video.akamai.steamstatic.com/store_trailers/1/dash_av1.mpd",
+        );
+        let variants = stream.parse_manifest(mpd);
+
+        assert_eq!(
+            variants,
+            [
+                StreamVariant {
+                    width: Some(1280),
+                    height: Some(720),
+                    codecs: vec!["av01.0.08M.08".to_owned()],
+                    bandwidth: Some(900_000),
+                    uri: Some("720p/video.mp4".to_owned()),
+                },
+                StreamVariant {
+                    width: Some(1920),
+                    height: Some(1080),
+                    codecs: vec!["av01.0.09M.08".to_owned()],
+                    bandwidth: Some(2_500_000),
+                    uri: None,
+                },
+            ]
+        );
+        assert_eq!(
+            stream.variant_url(&variants[0]).as_deref(),
+            Some("https://video.akamai.steamstatic.com/store_trailers/1/720p/video.mp4")
+        );
+        assert_eq!(stream.variant_url(&variants[1]), None);
+    }
+}
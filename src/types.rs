@@ -1,7 +1,18 @@
+use std::fmt;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
+use crate::lenient::Extra;
+use crate::release::ReleasePeriod;
+use crate::steam::SteamErr;
+
 /// languages supported by Steam store
-#[derive(Default, Clone, Debug, Hash)]
+///
+/// Serialized as the store API name, e.g. `english` or `brazilian`.
+/// Deserialized with [`FromStr`], which also accepts language codes and display names.
+#[derive(Default, Clone, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum Language {
     #[default]
     All,
@@ -37,6 +48,152 @@ pub enum Language {
     Vietnamese,
 }
 
+impl Language {
+    /// Every language, including `All`.
+    pub const ALL: [Language; 31] = {
+        use Language::*;
+        [
+            All, Arabic, Bulgarian, Schinese, Tchinese, Czech, Danish, Dutch, English, Finnish,
+            French, German, Greek, Hungarian, Indonesian, Italian, Japanese, Koreana, Norwegian,
+            Polish, Portuguese, Brazilian, Romanian, Russian, Spanish, Latam, Swedish, Thai,
+            Turkish, Ukrainian, Vietnamese,
+        ]
+    };
+
+    /// Display names used by the store: English name first, then the native one.
+    pub fn names(&self) -> &'static [&'static str] {
+        use Language::*;
+
+        match &self {
+            All => &["All Languages"],
+            Arabic => &["Arabic", "العربية"],
+            Bulgarian => &["Bulgarian", "български"],
+            Schinese => &["Simplified Chinese", "简体中文", "Chinese (Simplified)"],
+            Tchinese => &["Traditional Chinese", "繁體中文", "Chinese (Traditional)"],
+            Czech => &["Czech", "čeština"],
+            Danish => &["Danish", "Dansk"],
+            Dutch => &["Dutch", "Nederlands"],
+            English => &["English"],
+            Finnish => &["Finnish", "Suomi"],
+            French => &["French", "Français"],
+            German => &["German", "Deutsch"],
+            Greek => &["Greek", "Ελληνικά"],
+            Hungarian => &["Hungarian", "Magyar"],
+            Indonesian => &["Indonesian", "Bahasa Indonesia"],
+            Italian => &["Italian", "Italiano"],
+            Japanese => &["Japanese", "日本語"],
+            Koreana => &["Korean", "한국어"],
+            Norwegian => &["Norwegian", "Norsk"],
+            Polish => &["Polish", "Polski"],
+            Portuguese => &[
+                "Portuguese - Portugal",
+                "Português - Portugal",
+                "Portuguese",
+            ],
+            Brazilian => &["Portuguese - Brazil", "Português - Brasil"],
+            Romanian => &["Romanian", "Română"],
+            Russian => &["Russian", "Русский"],
+            Spanish => &["Spanish - Spain", "Español - España", "Spanish"],
+            Latam => &["Spanish - Latin America", "Español - Latinoamérica"],
+            Swedish => &["Swedish", "Svenska"],
+            Thai => &["Thai", "ไทย"],
+            Turkish => &["Turkish", "Türkçe"],
+            Ukrainian => &["Ukrainian", "Українська"],
+            Vietnamese => &["Vietnamese", "Tiếng Việt"],
+        }
+    }
+
+    /// ISO 639-1 code, e.g. `pt` for both Portuguese and Brazilian.
+    /// `None` for `All`.
+    pub fn iso_639_1(&self) -> Option<&'static str> {
+        use Language::*;
+
+        Some(match &self {
+            All => return None,
+            Arabic => "ar",
+            Bulgarian => "bg",
+            Schinese | Tchinese => "zh",
+            Czech => "cs",
+            Danish => "da",
+            Dutch => "nl",
+            English => "en",
+            Finnish => "fi",
+            French => "fr",
+            German => "de",
+            Greek => "el",
+            Hungarian => "hu",
+            Indonesian => "id",
+            Italian => "it",
+            Japanese => "ja",
+            Koreana => "ko",
+            Norwegian => "no",
+            Polish => "pl",
+            Portuguese | Brazilian => "pt",
+            Romanian => "ro",
+            Russian => "ru",
+            Spanish | Latam => "es",
+            Swedish => "sv",
+            Thai => "th",
+            Turkish => "tr",
+            Ukrainian => "uk",
+            Vietnamese => "vi",
+        })
+    }
+
+    /// BCP 47 language tag, e.g. `pt-BR` or `es-419`. `None` for `All`.
+    pub fn bcp47(&self) -> Option<&'static str> {
+        use Language::*;
+
+        match &self {
+            Schinese => Some("zh-CN"),
+            Tchinese => Some("zh-TW"),
+            Portuguese => Some("pt-PT"),
+            Brazilian => Some("pt-BR"),
+            Spanish => Some("es-ES"),
+            Latam => Some("es-419"),
+            l => l.iso_639_1(),
+        }
+    }
+
+    /// Code used by the Steam Web API, e.g. `en`, `pt-BR` or `vn`. `None` for `All`.
+    pub fn web_api_code(&self) -> Option<&'static str> {
+        use Language::*;
+
+        match &self {
+            Portuguese => Some("pt"),
+            Spanish => Some("es"),
+            Vietnamese => Some("vn"),
+            l => l.bcp47(),
+        }
+    }
+
+    /// Finds a language by store API name, Web API code, BCP 47 tag or
+    /// ISO 639-1 code, ignoring case. Bare `zh`, `pt` and `es` map to
+    /// Simplified Chinese, Portuguese and Spanish.
+    pub fn from_code(code: &str) -> Option<Language> {
+        let code = code.trim().replace('_', "-").to_lowercase();
+        let eq = |c: Option<&str>| c.is_some_and(|c| c.to_lowercase() == code);
+
+        Language::ALL
+            .iter()
+            .find(|l| l.as_ref() == code)
+            .or_else(|| Language::ALL.iter().find(|l| eq(l.web_api_code())))
+            .or_else(|| Language::ALL.iter().find(|l| eq(l.bcp47())))
+            .or_else(|| Language::ALL.iter().find(|l| eq(l.iso_639_1())))
+            .cloned()
+    }
+
+    /// Finds a language by store display name (English or native)
+    /// or by API name, ignoring case.
+    pub fn from_name(name: &str) -> Option<Language> {
+        let name = name.trim().to_lowercase();
+
+        Language::ALL
+            .into_iter()
+            .find(|l| l.as_ref() == name || l.names().iter().any(|n| n.to_lowercase() == name))
+    }
+}
+
 impl AsRef<str> for Language {
     fn as_ref(&self) -> &str {
         use Language::*;
@@ -77,16 +234,180 @@ impl AsRef<str> for Language {
     }
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+/// Type of an application, as returned by `appdetails`.
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum AppType {
+    Game,
+    Dlc,
+    Demo,
+    Advertising,
+    Mod,
+    Video,
+    Music,
+    Series,
+    Episode,
+    Hardware,
+    Application,
+    Tool,
+    /// Any value not known to this crate.
+    Unknown(String),
+}
+
+impl AppType {
+    /// Games, demos and mods, i.e. things that can be launched and played.
+    pub fn is_playable(&self) -> bool {
+        matches!(self, AppType::Game | AppType::Demo | AppType::Mod)
+    }
+}
+
+impl AsRef<str> for AppType {
+    fn as_ref(&self) -> &str {
+        use AppType::*;
+
+        match &self {
+            Game => "game",
+            Dlc => "dlc",
+            Demo => "demo",
+            Advertising => "advertising",
+            Mod => "mod",
+            Video => "video",
+            Music => "music",
+            Series => "series",
+            Episode => "episode",
+            Hardware => "hardware",
+            Application => "application",
+            Tool => "tool",
+            Unknown(s) => s,
+        }
+    }
+}
+
+impl From<String> for AppType {
+    fn from(s: String) -> Self {
+        use AppType::*;
+
+        match s.to_lowercase().as_str() {
+            "game" => Game,
+            "dlc" => Dlc,
+            "demo" => Demo,
+            "advertising" => Advertising,
+            "mod" => Mod,
+            "video" => Video,
+            "music" => Music,
+            "series" => Series,
+            "episode" => Episode,
+            "hardware" => Hardware,
+            "application" => Application,
+            "tool" => Tool,
+            _ => Unknown(s),
+        }
+    }
+}
+
+impl From<AppType> for String {
+    fn from(t: AppType) -> Self {
+        match t {
+            AppType::Unknown(s) => s,
+            t => t.as_ref().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AppType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// Type of an item in store listings, e.g. featured or genre tabs.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum ItemType {
+    App,
+    Package,
+    Bundle,
+    /// Any value not known to this crate.
+    Unknown(u8),
+}
+
+impl From<u8> for ItemType {
+    fn from(n: u8) -> Self {
+        match n {
+            0 => ItemType::App,
+            1 => ItemType::Package,
+            2 => ItemType::Bundle,
+            n => ItemType::Unknown(n),
+        }
+    }
+}
+
+impl From<ItemType> for u8 {
+    fn from(t: ItemType) -> Self {
+        match t {
+            ItemType::App => 0,
+            ItemType::Package => 1,
+            ItemType::Bundle => 2,
+            ItemType::Unknown(n) => n,
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// Accepts anything [`Language::from_code`] or [`Language::from_name`] does.
+impl FromStr for Language {
+    type Err = SteamErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Language::from_code(s)
+            .or_else(|| Language::from_name(s))
+            .ok_or_else(|| SteamErr::UnknownLanguage(s.to_owned()))
+    }
+}
+
+impl TryFrom<String> for Language {
+    type Error = SteamErr;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Language> for String {
+    fn from(l: Language) -> Self {
+        l.as_ref().to_owned()
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Platforms {
     pub windows: bool,
     pub mac: bool,
     pub linux: bool,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Deserialize, Serialize, Hash, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ReleaseDate {
     pub coming_soon: Option<bool>,
     pub date: Option<String>,
     pub steam: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl ReleaseDate {
+    /// Parses `date`, which is localized for the `language` used in the request.
+    /// Returns `None` if no date was provided.
+    pub fn parse(&self, language: &Language) -> Option<ReleasePeriod> {
+        self.date
+            .as_deref()
+            .map(|date| ReleasePeriod::parse(date, language))
+    }
 }